@@ -7,7 +7,7 @@ use std::fmt::Debug;
 use std::path::Path;
 
 use agnes::field::Value;
-use agnes::join::{Equal, Join};
+use agnes::join::{Equal, Join, RelabelMap};
 use agnes::source::csv::{CsvReader, CsvSource, IntoCsvSrcSpec};
 
 fn load_csv_file<Spec>(filename: &str, spec: Spec) -> CsvReader<Spec::CsvSrcSpec>
@@ -77,7 +77,8 @@ fn main() {
     dv_gdp_metadata.filter::<gdp_metadata::Region, _>(|val: Value<&String>| val.exists());
 
     let dv_gdp_joined = dv_gdp
-        .join::<Join<gdp::CountryCode, gdp_metadata::CountryCode, Equal>, _, _>(&dv_gdp_metadata);
+        .join::<Join<gdp::CountryCode, gdp_metadata::CountryCode, Equal>, _, _>(&dv_gdp_metadata)
+        .unwrap();
 
     let life_spec = spec![
         fieldname life::CountryCode = "Country Code";
@@ -91,10 +92,16 @@ fn main() {
         .v::<Labels![life::CountryCode, life::Year1983]>();
 
     let dv_gdp_joined = dv_gdp_joined.relabel::<gdp::Year1983, gdp_life::Gdp1983>();
-    let dv_life = dv_life.relabel::<life::Year1983, gdp_life::Life1983>();
 
+    // relabel the life expectancy field as part of the join
     let dv = dv_gdp_joined
-        .join::<Join<gdp::CountryCode, life::CountryCode, Equal>, _, _>(&dv_life)
+        .join::<Join<
+            gdp::CountryCode,
+            life::CountryCode,
+            Equal,
+            RelabelMap<life::Year1983, gdp_life::Life1983>,
+        >, _, _>(&dv_life)
+        .unwrap()
         .v::<Labels![gdp::CountryName, gdp_life::Gdp1983, gdp_life::Life1983]>();
 
     println!("{}", dv);
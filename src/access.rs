@@ -6,8 +6,10 @@ use std::cmp::Ordering;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
+use cons::{Cons, Nil};
 use error::*;
-use field::Value;
+use field::{FieldData, Value};
+use par::{stable_sort_order, MaybeSync};
 
 /// Trait that provides access to values in a data field.
 pub trait DataIndex: Debug {
@@ -306,18 +308,16 @@ pub trait SortOrder {
 
 impl<DI> SortOrder for DI
 where
-    DI: DataIndex,
+    DI: DataIndex + MaybeSync,
     <DI as DataIndex>::DType: Ord,
 {
     fn sort_order(&self) -> Vec<usize> {
-        let mut order = (0..self.len()).collect::<Vec<_>>();
-        order.sort_by(|&left, &right| {
+        stable_sort_order(self.len(), |left, right| {
             // a, b are always in range, so unwraps are safe
             self.get_datum(left)
                 .unwrap()
                 .cmp(&self.get_datum(right).unwrap())
-        });
-        order
+        })
     }
 }
 
@@ -458,6 +458,185 @@ where
     }
 }
 
+/// Trait providing method to provide an index permutation of records that match a predicate, where
+/// the predicate is applied to the values of each field in a cons-list of fields (implementing
+/// `DataIndex`). The predicate is called with one `Value<&T>` argument per field, in the order of
+/// the fields in the cons-list.
+pub trait FilterRecordsPerm<P> {
+    /// Returns the permutation indices of the records in this cons-list of fields which match the
+    /// specified `predicate`.
+    fn filter_records_perm(&self, predicate: P) -> Vec<usize>;
+}
+
+macro_rules! fields_cons {
+    () => { Nil };
+    ($field:ident $(, $rest:ident)*) => { Cons<$field, fields_cons![$($rest),*]> };
+}
+macro_rules! fields_pat {
+    () => { Nil };
+    ($value:ident $(, $rest:ident)*) => {
+        Cons { head: ref $value, tail: fields_pat![$($rest),*] }
+    };
+}
+macro_rules! impl_filter_records_perm {
+    ($($first_field:ident $first_value:ident $(, $field:ident $value:ident)*;)*) => {$(
+        impl<P, $first_field $(, $field)*> FilterRecordsPerm<P>
+            for fields_cons![$first_field $(, $field)*]
+        where
+            $first_field: DataIndex,
+            $($field: DataIndex,)*
+            P: FnMut(Value<&$first_field::DType> $(, Value<&$field::DType>)*) -> bool,
+        {
+            fn filter_records_perm(&self, mut predicate: P) -> Vec<usize> {
+                let fields_pat![$first_value $(, $value)*] = *self;
+                // all fields have the same length, so unwraps are safe
+                (0..$first_value.len())
+                    .filter(|&idx| {
+                        predicate(
+                            $first_value.get_datum(idx).unwrap()
+                            $(, $value.get_datum(idx).unwrap())*
+                        )
+                    })
+                    .collect()
+            }
+        }
+    )*}
+}
+impl_filter_records_perm![
+    F1 f1;
+    F1 f1, F2 f2;
+    F1 f1, F2 f2, F3 f3;
+    F1 f1, F2 f2, F3 f3, F4 f4;
+    F1 f1, F2 f2, F3 f3, F4 f4, F5 f5;
+    F1 f1, F2 f2, F3 f3, F4 f4, F5 f5, F6 f6;
+    F1 f1, F2 f2, F3 f3, F4 f4, F5 f5, F6 f6, F7 f7;
+    F1 f1, F2 f2, F3 f3, F4 f4, F5 f5, F6 f6, F7 f7, F8 f8;
+];
+
+/// Trait for counting the missing values in a record of a cons-list of fields (implementing
+/// `DataIndex`).
+pub trait RecordNaCount {
+    /// Returns the number of fields in this cons-list.
+    fn num_fields(&self) -> usize;
+    /// Returns the number of fields in this cons-list whose value at index `idx` is missing (or
+    /// which have no value at index `idx`).
+    fn record_num_na(&self, idx: usize) -> usize;
+}
+impl RecordNaCount for Nil {
+    fn num_fields(&self) -> usize {
+        0
+    }
+    fn record_num_na(&self, _idx: usize) -> usize {
+        0
+    }
+}
+impl<Head, Tail> RecordNaCount for Cons<Head, Tail>
+where
+    Head: DataIndex,
+    Tail: RecordNaCount,
+{
+    fn num_fields(&self) -> usize {
+        1 + self.tail.num_fields()
+    }
+    fn record_num_na(&self, idx: usize) -> usize {
+        let is_na = match self.head.get_datum(idx) {
+            Ok(Value::Exists(_)) => 0,
+            _ => 1,
+        };
+        is_na + self.tail.record_num_na(idx)
+    }
+}
+
+/// Trait providing method to compute a new field from the values of each record in a cons-list of
+/// fields (implementing `DataIndex`). The function is called with one `&T` argument per field, in
+/// the order of the fields in the cons-list. If any of the values of a record are missing, the
+/// function is not called and the computed value for that record is missing.
+pub trait ComputeRecords<F> {
+    /// The data type of the computed field.
+    type Output;
+
+    /// Returns the computed field, with one value for each record in this cons-list of fields.
+    fn compute_records(&self, f: F) -> FieldData<Self::Output>;
+}
+
+/// Trait providing method to compute a new field from the values of each record in a cons-list of
+/// fields (implementing `DataIndex`). The function is called with one `Value<&T>` argument per
+/// field, in the order of the fields in the cons-list, and returns a (possibly missing) `Value`.
+pub trait ComputeRecordValues<F> {
+    /// The data type of the computed field.
+    type Output;
+
+    /// Returns the computed field, with one value for each record in this cons-list of fields.
+    fn compute_record_values(&self, f: F) -> FieldData<Self::Output>;
+}
+
+macro_rules! impl_compute_records {
+    ($($first_field:ident $first_value:ident $(, $field:ident $value:ident)*;)*) => {$(
+        impl<F, O, $first_field $(, $field)*> ComputeRecords<F>
+            for fields_cons![$first_field $(, $field)*]
+        where
+            $first_field: DataIndex,
+            $($field: DataIndex,)*
+            F: FnMut(&$first_field::DType $(, &$field::DType)*) -> O,
+            O: Debug + Default,
+        {
+            type Output = O;
+
+            fn compute_records(&self, mut f: F) -> FieldData<O> {
+                let fields_pat![$first_value $(, $value)*] = *self;
+                // all fields have the same length, so unwraps are safe
+                (0..$first_value.len())
+                    .map(|idx| {
+                        match (
+                            $first_value.get_datum(idx).unwrap(),
+                            $($value.get_datum(idx).unwrap(),)*
+                        ) {
+                            (Value::Exists($first_value), $(Value::Exists($value),)*) => {
+                                Value::Exists(f($first_value $(, $value)*))
+                            }
+                            _ => Value::Na,
+                        }
+                    })
+                    .collect()
+            }
+        }
+
+        impl<F, O, $first_field $(, $field)*> ComputeRecordValues<F>
+            for fields_cons![$first_field $(, $field)*]
+        where
+            $first_field: DataIndex,
+            $($field: DataIndex,)*
+            F: FnMut(Value<&$first_field::DType> $(, Value<&$field::DType>)*) -> Value<O>,
+            O: Debug + Default,
+        {
+            type Output = O;
+
+            fn compute_record_values(&self, mut f: F) -> FieldData<O> {
+                let fields_pat![$first_value $(, $value)*] = *self;
+                // all fields have the same length, so unwraps are safe
+                (0..$first_value.len())
+                    .map(|idx| {
+                        f(
+                            $first_value.get_datum(idx).unwrap()
+                            $(, $value.get_datum(idx).unwrap())*
+                        )
+                    })
+                    .collect()
+            }
+        }
+    )*}
+}
+impl_compute_records![
+    F1 f1;
+    F1 f1, F2 f2;
+    F1 f1, F2 f2, F3 f3;
+    F1 f1, F2 f2, F3 f3, F4 f4;
+    F1 f1, F2 f2, F3 f3, F4 f4, F5 f5;
+    F1 f1, F2 f2, F3 f3, F4 f4, F5 f5, F6 f6;
+    F1 f1, F2 f2, F3 f3, F4 f4, F5 f5, F6 f6, F7 f7;
+    F1 f1, F2 f2, F3 f3, F4 f4, F5 f5, F6 f6, F7 f7, F8 f8;
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,409 @@
+/*!
+Traits for casting fields from one data type to another. Casts are supported between all primitive
+numeric types, from `String` to primitive numeric types and `bool` (by parsing), and from primitive
+numeric types and `bool` to `String`.
+
+Casts can be performed in a lossy mode ([cast](trait.Cast.html#method.cast)), where values which
+fail to convert become missing, or a checked mode
+([cast_checked](trait.Cast.html#method.cast_checked)), where any value which cannot be converted
+exactly results in an error. In lossy mode, floating-point values are truncated towards zero when
+cast to integer types, and lose precision as with the `as` operator when cast to smaller types;
+values which are outside the range of the target type (including NaN and infinite values cast to
+integer types) become missing.
+*/
+use std::fmt::Debug;
+
+use num_traits::{AsPrimitive, Bounded, Float, Zero};
+
+use access::DataIndex;
+use error;
+use field::{FieldData, Value};
+
+/// Trait for converting a single value into a value of type `Target`.
+pub trait CastValue<Target> {
+    /// Converts this value into `Target`, allowing loss of precision (truncating floating-point
+    /// values towards zero when converting to integer types). Returns `None` if this value cannot
+    /// be converted at all (e.g. a value outside the range of `Target`, or a string which cannot
+    /// be parsed as `Target`).
+    fn cast_lossy(&self) -> Option<Target>;
+    /// Converts this value into `Target`, returning `None` if the conversion fails or the
+    /// converted value does not exactly represent this value.
+    fn cast_exact(&self) -> Option<Target>;
+}
+
+fn int_to_int<Source, Target>(value: Source) -> Option<Target>
+where
+    Source: AsPrimitive<Target> + PartialOrd + Zero,
+    Target: AsPrimitive<Source> + PartialOrd + Zero,
+{
+    // an integer is within the range of the target type exactly when it survives a round trip
+    // with its sign intact
+    let converted: Target = value.as_();
+    let round_trip: Source = converted.as_();
+    if round_trip == value && (value < Source::zero()) == (converted < Target::zero()) {
+        Some(converted)
+    } else {
+        None
+    }
+}
+
+fn int_to_float<Source, Target>(value: Source) -> Option<Target>
+where
+    Source: AsPrimitive<Target>,
+    Target: Copy + 'static,
+{
+    Some(value.as_())
+}
+
+fn float_to_int<Source, Target>(value: Source) -> Option<Target>
+where
+    Source: Float + AsPrimitive<Target>,
+    Target: Bounded + AsPrimitive<Source>,
+{
+    let truncated = value.trunc();
+    // the maximum of an integer type is one less than a power of two, so `max + 1` is exactly
+    // representable (even when `max` itself is rounded)
+    let min: Source = Target::min_value().as_();
+    let max: Source = Target::max_value().as_();
+    if truncated >= min && truncated < max + Source::one() {
+        Some(truncated.as_())
+    } else {
+        None
+    }
+}
+
+fn float_to_float<Source, Target>(value: Source) -> Option<Target>
+where
+    Source: Float + AsPrimitive<Target>,
+    Target: Float + 'static,
+{
+    let converted: Target = value.as_();
+    if value.is_finite() && !converted.is_finite() {
+        None
+    } else {
+        Some(converted)
+    }
+}
+
+// `back` converts the converted value back into `Source` with the same range checks as the forward
+// conversion, so that a saturating conversion can't make an inexact value appear to round trip
+fn cast_exact_numeric<Source, Target, Back>(
+    value: Source,
+    converted: Option<Target>,
+    back: Back,
+) -> Option<Target>
+where
+    Source: PartialOrd + Copy,
+    Target: Copy,
+    Back: Fn(Target) -> Option<Source>,
+{
+    converted.and_then(|converted| {
+        let round_trip = back(converted)?;
+        // NaN is the only value which is not comparable to itself
+        let both_nan =
+            value.partial_cmp(&value).is_none() && round_trip.partial_cmp(&round_trip).is_none();
+        if both_nan || round_trip == value {
+            Some(converted)
+        } else {
+            None
+        }
+    })
+}
+
+macro_rules! impl_numeric_cast_from {
+    ($cast_fn:ident, $back_fn:ident; $source:ty; $($target:ty)*) => {$(
+        impl CastValue<$target> for $source {
+            fn cast_lossy(&self) -> Option<$target> {
+                $cast_fn(*self)
+            }
+            fn cast_exact(&self) -> Option<$target> {
+                cast_exact_numeric(
+                    *self,
+                    $cast_fn::<$source, $target>(*self),
+                    $back_fn::<$target, $source>,
+                )
+            }
+        }
+    )*}
+}
+
+macro_rules! impl_numeric_casts {
+    (int: $($source:ty)*) => {$(
+        impl_numeric_cast_from![int_to_int, int_to_int; $source;
+            u8 u16 u32 u64 usize i8 i16 i32 i64 isize];
+        impl_numeric_cast_from![int_to_float, float_to_int; $source; f32 f64];
+    )*};
+    (float: $($source:ty)*) => {$(
+        impl_numeric_cast_from![float_to_int, int_to_float; $source;
+            u8 u16 u32 u64 usize i8 i16 i32 i64 isize];
+        impl_numeric_cast_from![float_to_float, float_to_float; $source; f32 f64];
+    )*};
+}
+
+impl_numeric_casts![int: u8 u16 u32 u64 usize i8 i16 i32 i64 isize];
+impl_numeric_casts![float: f32 f64];
+
+macro_rules! impl_string_casts {
+    ($($dtype:ty)*) => {$(
+        impl CastValue<String> for $dtype {
+            fn cast_lossy(&self) -> Option<String> {
+                Some(self.to_string())
+            }
+            fn cast_exact(&self) -> Option<String> {
+                Some(self.to_string())
+            }
+        }
+
+        impl CastValue<$dtype> for String {
+            fn cast_lossy(&self) -> Option<$dtype> {
+                self.trim().parse().ok()
+            }
+            fn cast_exact(&self) -> Option<$dtype> {
+                self.trim().parse().ok()
+            }
+        }
+    )*}
+}
+
+impl_string_casts![u8 u16 u32 u64 usize i8 i16 i32 i64 isize f32 f64 bool];
+
+impl CastValue<String> for String {
+    fn cast_lossy(&self) -> Option<String> {
+        Some(self.clone())
+    }
+    fn cast_exact(&self) -> Option<String> {
+        Some(self.clone())
+    }
+}
+
+/// Trait for casting a field into a field of a different data type.
+pub trait Cast: DataIndex + Sized {
+    /// Casts this field into a field of type `Target`, allowing loss of precision: floating-point
+    /// values are truncated towards zero when cast to integer types, and rounded when cast to
+    /// smaller floating-point types. Values which cannot be converted (values outside the range
+    /// of `Target`, NaN or infinite values cast to integer types, or strings which cannot be
+    /// parsed as `Target`) become missing values.
+    fn cast<Target>(&self) -> FieldData<Target>
+    where
+        Self::DType: CastValue<Target>,
+        Target: Debug + Default,
+    {
+        self.iter()
+            .map(|value| match value {
+                Value::Exists(value) => value.cast_lossy().into(),
+                Value::Na => Value::Na,
+            })
+            .collect()
+    }
+
+    /// Casts this field into a field of type `Target`, returning a `TypeMismatch` error if any
+    /// existing value cannot be exactly converted into `Target` (e.g. a negative value cast to an
+    /// unsigned type, a fractional value cast to an integer type, or a string which cannot be
+    /// parsed as `Target`). Missing values remain missing.
+    fn cast_checked<Target>(&self) -> error::Result<FieldData<Target>>
+    where
+        Self::DType: CastValue<Target> + Debug,
+        Target: Debug + Default,
+    {
+        self.iter()
+            .enumerate()
+            .map(|(idx, value)| match value {
+                Value::Exists(value) => match value.cast_exact() {
+                    Some(converted) => Ok(Value::Exists(converted)),
+                    None => Err(error::AgnesError::TypeMismatch(format!(
+                        "unable to cast value {:?} at index {}",
+                        value, idx
+                    ))),
+                },
+                Value::Na => Ok(Value::Na),
+            })
+            .collect()
+    }
+}
+
+impl<DI> Cast for DI where DI: DataIndex {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numeric() {
+        let field =
+            FieldData::from_field_vec(vec![Value::Exists(3u64), Value::Na, Value::Exists(1 << 40)]);
+        let expected = vec![
+            Value::Exists(3.0f64),
+            Value::Na,
+            Value::Exists(1099511627776.0),
+        ];
+        assert_eq!(field.cast::<f64>().to_value_vec(), expected);
+        assert_eq!(
+            field.cast_checked::<f64>().unwrap().to_value_vec(),
+            expected
+        );
+
+        // lossy conversions
+        assert_eq!(
+            field.cast::<u32>().to_value_vec(),
+            vec![Value::Exists(3u32), Value::Na, Value::Na]
+        );
+        assert!(field.cast_checked::<u32>().is_err());
+        let field = FieldData::<f64>::from_vec(vec![-1.5f64, 2.0, -0.5, ::std::f64::NAN]);
+        assert_eq!(
+            field.cast::<i64>().to_value_vec(),
+            vec![
+                Value::Exists(-1i64),
+                Value::Exists(2),
+                Value::Exists(0),
+                Value::Na
+            ]
+        );
+        assert_eq!(
+            field.cast::<u8>().to_value_vec(),
+            vec![Value::Na, Value::Exists(2u8), Value::Exists(0), Value::Na]
+        );
+        assert!(field.cast_checked::<i64>().is_err());
+        let field = FieldData::<i32>::from_vec(vec![-1i32, 2]);
+        assert!(field.cast_checked::<u64>().is_err());
+        assert_eq!(field.cast_checked::<i8>().unwrap().to_vec(), vec![-1i8, 2]);
+
+        // NaN is preserved when casting between floating-point types
+        let field = FieldData::<f64>::from_vec(vec![0.5f64, ::std::f64::NAN]);
+        let cast = field.cast_checked::<f32>().unwrap().to_vec();
+        assert_eq!(cast[0], 0.5f32);
+        assert!(cast[1].is_nan());
+        assert!(FieldData::<f64>::from_vec(vec![0.1f64])
+            .cast_checked::<f32>()
+            .is_err());
+        assert_eq!(
+            FieldData::<u8>::from_vec(vec![255u8, 127])
+                .cast::<i8>()
+                .to_value_vec(),
+            vec![Value::Na, Value::Exists(127i8)]
+        );
+    }
+
+    #[test]
+    fn numeric_range() {
+        // the largest `f64` values below 2^64 and 2^63, and the boundaries themselves
+        let below_u64 = 18_446_744_073_709_549_568.0f64;
+        let below_i64 = 9_223_372_036_854_774_784.0f64;
+        let field = FieldData::<f64>::from_vec(vec![below_u64, 18_446_744_073_709_551_616.0]);
+        assert_eq!(
+            field.cast::<u64>().to_value_vec(),
+            vec![Value::Exists(18_446_744_073_709_549_568u64), Value::Na]
+        );
+        assert!(field.cast_checked::<u64>().is_err());
+        assert!(FieldData::<f64>::from_vec(vec![below_u64])
+            .cast_checked::<u64>()
+            .is_ok());
+
+        let field = FieldData::<f64>::from_vec(vec![
+            below_i64,
+            -9_223_372_036_854_775_808.0,
+            9_223_372_036_854_775_808.0,
+        ]);
+        assert_eq!(
+            field.cast::<i64>().to_value_vec(),
+            vec![
+                Value::Exists(9_223_372_036_854_774_784i64),
+                Value::Exists(::std::i64::MIN),
+                Value::Na
+            ]
+        );
+        assert!(field.cast_checked::<i64>().is_err());
+        assert_eq!(
+            FieldData::<f64>::from_vec(vec![below_i64, -9_223_372_036_854_775_808.0])
+                .cast_checked::<i64>()
+                .unwrap()
+                .to_vec(),
+            vec![9_223_372_036_854_774_784i64, ::std::i64::MIN]
+        );
+
+        // the largest integers round up to a power of two as floating-point values, which is
+        // outside their range
+        let field = FieldData::<u64>::from_vec(vec![::std::u64::MAX]);
+        assert_eq!(
+            field.cast::<f64>().to_vec(),
+            vec![18_446_744_073_709_551_616.0f64]
+        );
+        assert!(field.cast_checked::<f64>().is_err());
+        let field = FieldData::<i64>::from_vec(vec![::std::i64::MAX]);
+        assert!(field.cast_checked::<f64>().is_err());
+        assert!(field.cast_checked::<f32>().is_err());
+        let field = FieldData::<i64>::from_vec(vec![::std::i64::MIN, 1 << 53]);
+        assert_eq!(
+            field.cast_checked::<f64>().unwrap().to_vec(),
+            vec![-9_223_372_036_854_775_808.0f64, 9_007_199_254_740_992.0]
+        );
+        assert!(FieldData::<i64>::from_vec(vec![(1i64 << 53) + 1])
+            .cast_checked::<f64>()
+            .is_err());
+
+        // NaN and infinite values cannot be cast to integer types
+        let field = FieldData::<f32>::from_vec(vec![::std::f32::INFINITY, ::std::f32::NAN, 255.9]);
+        assert_eq!(
+            field.cast::<u8>().to_value_vec(),
+            vec![Value::Na, Value::Na, Value::Exists(255u8)]
+        );
+        // finite values too large for a smaller floating-point type
+        let field = FieldData::<f64>::from_vec(vec![1e300f64, ::std::f64::INFINITY]);
+        assert_eq!(
+            field.cast::<f32>().to_value_vec(),
+            vec![Value::Na, Value::Exists(::std::f32::INFINITY)]
+        );
+        assert!(field.cast_checked::<f32>().is_err());
+    }
+
+    #[test]
+    fn strings() {
+        let field = FieldData::from_field_vec(vec![
+            Value::Exists("42".to_string()),
+            Value::Exists(" -7 ".to_string()),
+            Value::Na,
+            Value::Exists("n/a".to_string()),
+        ]);
+        assert_eq!(
+            field.cast::<i64>().to_value_vec(),
+            vec![
+                Value::Exists(42i64),
+                Value::Exists(-7),
+                Value::Na,
+                Value::Na
+            ]
+        );
+        assert_eq!(
+            field.cast::<u64>().to_value_vec(),
+            vec![Value::Exists(42u64), Value::Na, Value::Na, Value::Na]
+        );
+        match field.cast_checked::<f64>() {
+            Err(error::AgnesError::TypeMismatch(_)) => {}
+            _ => panic!("expected TypeMismatch error"),
+        }
+        assert_eq!(
+            FieldData::<String>::from_vec(vec!["1.5".to_string(), "-2".to_string()])
+                .cast_checked::<f64>()
+                .unwrap()
+                .to_vec(),
+            vec![1.5, -2.0]
+        );
+        assert_eq!(
+            FieldData::<String>::from_vec(vec!["true".to_string(), "false".to_string()])
+                .cast::<bool>()
+                .to_vec(),
+            vec![true, false]
+        );
+
+        let field = FieldData::from_field_vec(vec![Value::Exists(1.5f64), Value::Na]);
+        assert_eq!(
+            field.cast_checked::<String>().unwrap().to_value_vec(),
+            vec![Value::Exists("1.5".to_string()), Value::Na]
+        );
+        assert_eq!(
+            FieldData::<bool>::from_vec(vec![true])
+                .cast::<String>()
+                .to_vec(),
+            vec!["true".to_string()]
+        );
+    }
+}
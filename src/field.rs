@@ -316,7 +316,7 @@ where
     pub fn from_field_vec(mut v: Vec<Value<T>>) -> FieldData<T> {
         let mut ret = FieldData::default();
         for elem in v.drain(..) {
-            ret.push(elem);
+            ret.push_val(elem);
         }
         ret
     }
@@ -328,7 +328,7 @@ where
     fn from_iter<I: IntoIterator<Item = Value<T>>>(iter: I) -> Self {
         let mut data = FieldData::default();
         for value in iter {
-            data.push(value);
+            data.push_val(value);
         }
         data
     }
@@ -340,7 +340,7 @@ where
     fn from_iter<I: IntoIterator<Item = Value<&'a T>>>(iter: I) -> Self {
         let mut data = FieldData::default();
         for value in iter {
-            data.push(value.cloned());
+            data.push_val(value.cloned());
         }
         data
     }
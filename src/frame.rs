@@ -6,7 +6,6 @@ underlying data store, along with record-based filtering and sorting details.
 #[cfg(feature = "serialize")]
 use serde::ser::{Serialize, SerializeSeq, Serializer};
 use std::fmt::Debug;
-use std::rc::Rc;
 use std::sync::Arc;
 
 use access::{self, DataIndex};
@@ -25,7 +24,7 @@ where
     Fields: AssocStorage,
     Fields::Storage: Debug,
 {
-    permutation: Rc<Permutation>,
+    permutation: Arc<Permutation>,
     store: Arc<DataStore<Fields>>,
 }
 impl<Fields> DataFrame<Fields>
@@ -72,7 +71,28 @@ where
     //     self.store.has_field(s)
     // }
     pub(crate) fn update_permutation(&mut self, new_permutation: &[usize]) {
-        Rc::make_mut(&mut self.permutation).update(new_permutation);
+        Arc::make_mut(&mut self.permutation).update(new_permutation);
+    }
+    pub(crate) fn reset_permutation(&mut self) {
+        self.permutation = Arc::new(Permutation::default());
+    }
+}
+impl<Fields> DataFrame<Fields>
+where
+    Fields: AssocStorage,
+    DataStore<Fields>: NRows,
+{
+    /// Returns the number of rows in the underlying data store of this `DataFrame` (ignoring any
+    /// filtering or sorting).
+    pub fn store_nrows(&self) -> usize {
+        self.store.nrows()
+    }
+    /// Returns the index of the record in the underlying data store for each row in this
+    /// `DataFrame`.
+    pub fn row_ids(&self) -> Vec<usize> {
+        (0..self.len())
+            .map(|idx| self.permutation.map_index(idx))
+            .collect()
     }
 }
 
@@ -82,7 +102,7 @@ where
 {
     fn from(store: DataStore<Fields>) -> DataFrame<Fields> {
         DataFrame {
-            permutation: Rc::new(Permutation::default()),
+            permutation: Arc::new(Permutation::default()),
             store: Arc::new(store),
         }
     }
@@ -93,19 +113,19 @@ where
 /// frame.
 #[derive(Debug, Hash, PartialEq, Eq)]
 pub struct Framed<T> {
-    permutation: Rc<Permutation>,
+    permutation: Arc<Permutation>,
     data: DataRef<T>,
 }
 impl<T> Framed<T> {
     /// Create a new framed view of some data, as view through a particular `DataFrame`.
-    pub fn new(permutation: Rc<Permutation>, data: DataRef<T>) -> Framed<T> {
+    pub fn new(permutation: Arc<Permutation>, data: DataRef<T>) -> Framed<T> {
         Framed { permutation, data }
     }
 }
 impl<T> Clone for Framed<T> {
     fn clone(&self) -> Framed<T> {
         Framed {
-            permutation: Rc::clone(&self.permutation),
+            permutation: Arc::clone(&self.permutation),
             data: DataRef::clone(&self.data),
         }
     }
@@ -113,7 +133,7 @@ impl<T> Clone for Framed<T> {
 impl<T> From<DataRef<T>> for Framed<T> {
     fn from(orig: DataRef<T>) -> Framed<T> {
         Framed {
-            permutation: Rc::new(Permutation::default()),
+            permutation: Arc::new(Permutation::default()),
             data: orig,
         }
     }
@@ -121,7 +141,7 @@ impl<T> From<DataRef<T>> for Framed<T> {
 impl<T> From<FieldData<T>> for Framed<T> {
     fn from(orig: FieldData<T>) -> Framed<T> {
         Framed {
-            permutation: Rc::new(Permutation::default()),
+            permutation: Arc::new(Permutation::default()),
             data: orig.into(),
         }
     }
@@ -174,7 +194,7 @@ where
 
     fn select_field(&self) -> Self::Output {
         Framed::new(
-            Rc::clone(&self.permutation),
+            Arc::clone(&self.permutation),
             DataRef::clone(&self.store.field::<Label>()),
         )
     }
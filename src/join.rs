@@ -6,8 +6,10 @@ refers to combining fields of two `DataView` objects with the same number of row
 `DataView`.
 */
 use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::hash::Hash;
 use std::marker::PhantomData;
-use std::ops::Add;
+use std::ops::{Add, Sub};
 
 use access::*;
 use cons::*;
@@ -139,9 +141,59 @@ where
 
 /// Marker struct describing a join. `LLabel` is the label of the left-hand side, `RLabel` is the
 /// label of the right-hand side, and `Predicate` represents the type of join predicate (equal join,
-/// greater-than join, less-than join, etc.).
-pub struct Join<LLabel, RLabel, Predicate> {
-    _marker: PhantomData<(LLabel, RLabel, Predicate)>,
+/// greater-than join, less-than join, etc.). `RightRelabel` specifies how the labels of the
+/// right-hand side are changed in the join result (see [JoinRelabel](trait.JoinRelabel.html)); by
+/// default they are [Unchanged](struct.Unchanged.html).
+pub struct Join<LLabel, RLabel, Predicate, RightRelabel = Unchanged> {
+    _marker: PhantomData<(LLabel, RLabel, Predicate, RightRelabel)>,
+}
+
+/// Relabeling marker which leaves the right-hand side labels of a join unchanged.
+pub struct Unchanged;
+/// Relabeling marker which replaces each right-hand side label `L` of a join with
+/// [Suffixed](../label/struct.Suffixed.html)`<L, S>`, appending the suffix `S` (`"_right"` by
+/// default) to each right-hand side field name.
+pub struct AddSuffix<S = RightSuffix> {
+    _marker: PhantomData<S>,
+}
+/// Relabeling marker which replaces the right-hand side label `CurrLabel` with `NewLabel`, and
+/// then applies the relabeling `Tail`. Multiple relabelings can be chained together, e.g.
+/// `RelabelMap<life::Year1983, gdp_life::Life1983, RelabelMap<life::Year1984, gdp_life::Life1984>>`.
+pub struct RelabelMap<CurrLabel, NewLabel, Tail = Unchanged> {
+    _marker: PhantomData<(CurrLabel, NewLabel, Tail)>,
+}
+
+/// Trait for computing the labels of the right-hand side of a join result from the right-hand
+/// side's label lookup list `Labels`.
+pub trait JoinRelabel<Labels> {
+    /// The label lookup list after relabeling.
+    type Output;
+}
+impl<Labels> JoinRelabel<Labels> for Unchanged {
+    type Output = Labels;
+}
+impl<S> JoinRelabel<Nil> for AddSuffix<S> {
+    type Output = Nil;
+}
+impl<S, Label, FrameIndex, FrameLabel, Tail>
+    JoinRelabel<FrameLookupCons<Label, FrameIndex, FrameLabel, Tail>> for AddSuffix<S>
+where
+    AddSuffix<S>: JoinRelabel<Tail>,
+{
+    type Output = FrameLookupCons<
+        Suffixed<Label, S>,
+        FrameIndex,
+        FrameLabel,
+        <AddSuffix<S> as JoinRelabel<Tail>>::Output,
+    >;
+}
+impl<CurrLabel, NewLabel, Tail, Labels> JoinRelabel<Labels>
+    for RelabelMap<CurrLabel, NewLabel, Tail>
+where
+    Labels: Relabel<CurrLabel, NewLabel>,
+    Tail: JoinRelabel<<Labels as Relabel<CurrLabel, NewLabel>>::Output>,
+{
+    type Output = <Tail as JoinRelabel<<Labels as Relabel<CurrLabel, NewLabel>>::Output>>::Output;
 }
 
 /// A trait for describing the course of action in a sort-merge join. This trait differentiates
@@ -332,16 +384,23 @@ pub trait SortMergeJoin<RLabels, RFrames, Join> {
     /// Resultant data structure after join.
     type Output;
 
-    /// Join this object with a `DataView`, using the join details specified with `Join`.
-    fn join(&self, right: &DataView<RLabels, RFrames>) -> Self::Output;
+    /// Join this object with a `DataView`, using the join details specified with `Join`. If either
+    /// side of the join has no rows (or no rows with existing key values), the result is an empty
+    /// `DataView` with the joined fields.
+    fn join(&self, right: &DataView<RLabels, RFrames>) -> Result<Self::Output>;
 }
-impl<LLabels, LFrames, RLabels, RFrames, LLabel, RLabel, Pred>
-    SortMergeJoin<RLabels, RFrames, Join<LLabel, RLabel, Pred>> for DataView<LLabels, LFrames>
+impl<LLabels, LFrames, RLabels, RFrames, LLabel, RLabel, Pred, RightRelabel>
+    SortMergeJoin<RLabels, RFrames, Join<LLabel, RLabel, Pred, RightRelabel>>
+    for DataView<LLabels, LFrames>
 where
+    RightRelabel: JoinRelabel<RLabels>,
     LFrames: JoinIntoStore<LLabels, DataStore<Nil>>,
-    RFrames: JoinIntoStore<RLabels, <LFrames as JoinIntoStore<LLabels, DataStore<Nil>>>::Output>,
+    RFrames: JoinIntoStore<
+        <RightRelabel as JoinRelabel<RLabels>>::Output,
+        <LFrames as JoinIntoStore<LLabels, DataStore<Nil>>>::Output,
+    >,
     <RFrames as JoinIntoStore<
-        RLabels,
+        <RightRelabel as JoinRelabel<RLabels>>::Output,
         <LFrames as JoinIntoStore<LLabels, DataStore<Nil>>>::Output,
     >>::Output: IntoView,
     Self: SelectFieldByLabel<LLabel>,
@@ -353,28 +412,28 @@ where
     Pred: Predicate,
 {
     type Output = <<RFrames as JoinIntoStore<
-        RLabels,
+        <RightRelabel as JoinRelabel<RLabels>>::Output,
         <LFrames as JoinIntoStore<LLabels, DataStore<Nil>>>::Output,
     >>::Output as IntoView>::Output;
 
-    fn join(&self, right: &DataView<RLabels, RFrames>) -> Self::Output {
+    fn join(&self, right: &DataView<RLabels, RFrames>) -> Result<Self::Output> {
         let left = self;
-        //TODO: return empty dataview if left or right is empty
 
+        // merge indices will be empty if either side is empty, which results in a store with
+        // the joined fields but no rows
         let merge_indices =
             merge_indices::<Pred, _, _>(&left.field::<LLabel>(), &right.field::<RLabel>());
 
         let store = DataStore::<Nil>::empty();
 
-        let store = left
-            .frames
-            .join_into_store(store, &merge_indices.0)
-            .unwrap();
-        let store = right
-            .frames
-            .join_into_store(store, &merge_indices.1)
-            .unwrap();
-        store.into_view()
+        let store = left.frames.join_into_store(store, &merge_indices.0)?;
+        let store =
+            JoinIntoStore::<<RightRelabel as JoinRelabel<RLabels>>::Output, _>::join_into_store(
+                &right.frames,
+                store,
+                &merge_indices.1,
+            )?;
+        Ok(store.into_view())
     }
 }
 
@@ -388,7 +447,10 @@ where
     let left_order = left_key_data.sort_order();
     let right_order = right_key_data.sort_order();
 
-    debug_assert!(!left_order.is_empty() && !right_order.is_empty());
+    // nothing can match if either side is empty
+    if left_order.is_empty() || right_order.is_empty() {
+        return (vec![], vec![]);
+    }
     // NOTE: actual_idx = perm[sorted_idx]
     // NOTE: value = key_data.get(actual_idx).unwrap();
 
@@ -468,6 +530,298 @@ where
     (left_merge_indices, right_merge_indices)
 }
 
+/// Predicate marker for as-of (nearest-key) joins. Each record on the left-hand side is matched
+/// to the single record on the right-hand side with the nearest key in the direction specified by
+/// `Direction` ([Backward](struct.Backward.html) or [Forward](struct.Forward.html)). If `ByKey` is
+/// a [By](struct.By.html) marker, records are additionally required to have equal values in the
+/// specified 'by' fields.
+///
+/// Left-hand records without a matching right-hand record are not included in the join result.
+/// When several right-hand records share the nearest key, the last one (in original order) is
+/// chosen for backward joins and the first one for forward joins.
+pub struct AsOf<Direction, ByKey = NoBy> {
+    _marker: PhantomData<(Direction, ByKey)>,
+}
+
+/// Marker for an as-of join direction which matches the nearest right-hand key at or before the
+/// left-hand key (right <= left).
+pub struct Backward;
+/// Marker for an as-of join direction which matches the nearest right-hand key at or after the
+/// left-hand key (right >= left).
+pub struct Forward;
+
+/// Trait describing the direction of an as-of join.
+pub trait AsOfDirection {
+    /// Returns `true` if this direction searches for right-hand keys at or before the left-hand
+    /// key, `false` if it searches for keys at or after the left-hand key.
+    fn is_backward() -> bool;
+}
+impl AsOfDirection for Backward {
+    fn is_backward() -> bool {
+        true
+    }
+}
+impl AsOfDirection for Forward {
+    fn is_backward() -> bool {
+        false
+    }
+}
+
+/// Marker for an as-of join without any 'by' (equality) fields.
+pub struct NoBy;
+/// Marker for an as-of join which requires the left-hand field `LLabel` to equal the right-hand
+/// field `RLabel` in addition to the nearest-key match.
+pub struct By<LLabel, RLabel> {
+    _marker: PhantomData<(LLabel, RLabel)>,
+}
+
+/// Trait for partitioning the sorted key orders of an as-of join into groups of records which are
+/// allowed to match each other. `Left` and `Right` are the two `DataView`s being joined.
+pub trait AsOfGroups<Left, Right> {
+    /// Partitions the (sorted) `left_order` and `right_order` indices into groups which share the
+    /// same 'by' values. Relative order within each group is preserved.
+    fn groups(
+        left: &Left,
+        right: &Right,
+        left_order: Vec<usize>,
+        right_order: Vec<usize>,
+    ) -> Vec<(Vec<usize>, Vec<usize>)>;
+}
+impl<Left, Right> AsOfGroups<Left, Right> for NoBy {
+    fn groups(
+        _left: &Left,
+        _right: &Right,
+        left_order: Vec<usize>,
+        right_order: Vec<usize>,
+    ) -> Vec<(Vec<usize>, Vec<usize>)> {
+        vec![(left_order, right_order)]
+    }
+}
+impl<Left, Right, LLabel, RLabel> AsOfGroups<Left, Right> for By<LLabel, RLabel>
+where
+    Left: SelectFieldByLabel<LLabel>,
+    Right: SelectFieldByLabel<RLabel>,
+    VFieldOf<Right, RLabel>: DataIndex<DType = VFieldTypeOf<Left, LLabel>>,
+    VFieldTypeOf<Left, LLabel>: Hash + Eq,
+{
+    fn groups(
+        left: &Left,
+        right: &Right,
+        left_order: Vec<usize>,
+        right_order: Vec<usize>,
+    ) -> Vec<(Vec<usize>, Vec<usize>)> {
+        let left_by = left.select_field();
+        let right_by = right.select_field();
+
+        let mut group_indices = HashMap::new();
+        let mut groups: Vec<(Vec<usize>, Vec<usize>)> = vec![];
+        for ridx in right_order {
+            // NAs shouldn't match, so records with missing 'by' values are left out
+            let value = right_by.get_datum(ridx).unwrap();
+            if value.exists() {
+                let group_idx = *group_indices.entry(value).or_insert_with(|| {
+                    groups.push((vec![], vec![]));
+                    groups.len() - 1
+                });
+                groups[group_idx].1.push(ridx);
+            }
+        }
+        for lidx in left_order {
+            if let Some(&group_idx) = group_indices.get(&left_by.get_datum(lidx).unwrap()) {
+                groups[group_idx].0.push(lidx);
+            }
+        }
+        groups
+    }
+}
+
+/// A trait for performing an as-of (nearest-key) join of a
+/// [DataView](../view/struct.DataView.html) with the current object using specified `Join`.
+/// `RLabels` and `RFrames` are the `Labels` and `Frames` type parameters for the `DataView` to
+/// merge.
+pub trait AsOfJoin<RLabels, RFrames, Join> {
+    /// Resultant data structure after join.
+    type Output;
+    /// Data type of the join key fields.
+    type Key;
+
+    /// Join this object with a `DataView`, using the as-of join details specified with `Join`. If
+    /// `tolerance` is provided, records are only matched if the distance between their keys is
+    /// less than or equal to `tolerance`.
+    fn join_asof(
+        &self,
+        right: &DataView<RLabels, RFrames>,
+        tolerance: Option<Self::Key>,
+    ) -> Result<Self::Output>;
+}
+impl<LLabels, LFrames, RLabels, RFrames, LLabel, RLabel, Dir, ByKey, RightRelabel>
+    AsOfJoin<RLabels, RFrames, Join<LLabel, RLabel, AsOf<Dir, ByKey>, RightRelabel>>
+    for DataView<LLabels, LFrames>
+where
+    RightRelabel: JoinRelabel<RLabels>,
+    LFrames: JoinIntoStore<LLabels, DataStore<Nil>>,
+    RFrames: JoinIntoStore<
+        <RightRelabel as JoinRelabel<RLabels>>::Output,
+        <LFrames as JoinIntoStore<LLabels, DataStore<Nil>>>::Output,
+    >,
+    <RFrames as JoinIntoStore<
+        <RightRelabel as JoinRelabel<RLabels>>::Output,
+        <LFrames as JoinIntoStore<LLabels, DataStore<Nil>>>::Output,
+    >>::Output: IntoView,
+    Self: SelectFieldByLabel<LLabel>,
+    <Self as SelectFieldByLabel<LLabel>>::Output: SortOrder,
+    VFieldTypeOf<Self, LLabel>: Ord + Clone + Sub<Output = VFieldTypeOf<Self, LLabel>>,
+    DataView<RLabels, RFrames>: SelectFieldByLabel<RLabel>,
+    <DataView<RLabels, RFrames> as SelectFieldByLabel<RLabel>>::Output: SortOrder,
+    VFieldOf<DataView<RLabels, RFrames>, RLabel>: DataIndex<DType = VFieldTypeOf<Self, LLabel>>,
+    Dir: AsOfDirection,
+    ByKey: AsOfGroups<Self, DataView<RLabels, RFrames>>,
+{
+    type Output = <<RFrames as JoinIntoStore<
+        <RightRelabel as JoinRelabel<RLabels>>::Output,
+        <LFrames as JoinIntoStore<LLabels, DataStore<Nil>>>::Output,
+    >>::Output as IntoView>::Output;
+    type Key = VFieldTypeOf<Self, LLabel>;
+
+    fn join_asof(
+        &self,
+        right: &DataView<RLabels, RFrames>,
+        tolerance: Option<Self::Key>,
+    ) -> Result<Self::Output> {
+        let left = self;
+
+        let left_key_data = left.field::<LLabel>();
+        let right_key_data = right.field::<RLabel>();
+        let left_order = left_key_data.sort_order();
+        let groups = ByKey::groups(left, right, left_order.clone(), right_key_data.sort_order());
+        let merge_indices = asof_merge_indices::<Dir, _, _>(
+            &left_key_data,
+            &right_key_data,
+            &left_order,
+            groups,
+            tolerance,
+        );
+
+        let store = DataStore::<Nil>::empty();
+
+        let store = left.frames.join_into_store(store, &merge_indices.0)?;
+        let store =
+            JoinIntoStore::<<RightRelabel as JoinRelabel<RLabels>>::Output, _>::join_into_store(
+                &right.frames,
+                store,
+                &merge_indices.1,
+            )?;
+        Ok(store.into_view())
+    }
+}
+impl<LLabels, LFrames, RLabels, RFrames, LLabel, RLabel, Dir, ByKey, RightRelabel>
+    SortMergeJoin<RLabels, RFrames, Join<LLabel, RLabel, AsOf<Dir, ByKey>, RightRelabel>>
+    for DataView<LLabels, LFrames>
+where
+    Self: AsOfJoin<RLabels, RFrames, Join<LLabel, RLabel, AsOf<Dir, ByKey>, RightRelabel>>,
+{
+    type Output = <Self as AsOfJoin<
+        RLabels,
+        RFrames,
+        Join<LLabel, RLabel, AsOf<Dir, ByKey>, RightRelabel>,
+    >>::Output;
+
+    fn join(&self, right: &DataView<RLabels, RFrames>) -> Result<Self::Output> {
+        AsOfJoin::join_asof(self, right, None)
+    }
+}
+
+fn asof_merge_indices<Dir, T, U>(
+    left_key_data: &T,
+    right_key_data: &U,
+    left_order: &[usize],
+    groups: Vec<(Vec<usize>, Vec<usize>)>,
+    tolerance: Option<<T as DataIndex>::DType>,
+) -> (Vec<usize>, Vec<usize>)
+where
+    Dir: AsOfDirection,
+    T: DataIndex,
+    U: DataIndex<DType = <T as DataIndex>::DType>,
+    <T as DataIndex>::DType: Ord + Clone + Sub<Output = <T as DataIndex>::DType>,
+{
+    let lval = |actual_idx| left_key_data.get_datum(actual_idx).unwrap();
+    let rval = |actual_idx| right_key_data.get_datum(actual_idx).unwrap();
+
+    let mut left_merge_indices = vec![];
+    let mut right_merge_indices = vec![];
+    for (left_group, right_order) in groups {
+        // NAs sort first and shouldn't match, so skip past them
+        let mut right_idx = right_order
+            .iter()
+            .position(|&ridx| rval(ridx).exists())
+            .unwrap_or(right_order.len());
+        let right_start = right_idx;
+        for lidx in left_group {
+            let left_val = match lval(lidx) {
+                Value::Exists(left_val) => left_val,
+                Value::Na => continue,
+            };
+            let nearest = if Dir::is_backward() {
+                // advance past all right values at or before the left value; the last one is
+                // the nearest
+                while right_idx < right_order.len()
+                    && rval(right_order[right_idx]) <= Value::Exists(left_val)
+                {
+                    right_idx += 1;
+                }
+                if right_idx > right_start {
+                    Some(right_order[right_idx - 1])
+                } else {
+                    None
+                }
+            } else {
+                // advance past all right values before the left value; the next one is the
+                // nearest
+                while right_idx < right_order.len()
+                    && rval(right_order[right_idx]) < Value::Exists(left_val)
+                {
+                    right_idx += 1;
+                }
+                if right_idx < right_order.len() {
+                    Some(right_order[right_idx])
+                } else {
+                    None
+                }
+            };
+            if let Some(ridx) = nearest {
+                let right_val = rval(ridx).unwrap();
+                let within_tolerance = match tolerance {
+                    Some(ref tolerance) => {
+                        let distance = if Dir::is_backward() {
+                            left_val.clone() - right_val.clone()
+                        } else {
+                            right_val.clone() - left_val.clone()
+                        };
+                        distance <= *tolerance
+                    }
+                    None => true,
+                };
+                if within_tolerance {
+                    left_merge_indices.push(lidx);
+                    right_merge_indices.push(ridx);
+                }
+            }
+        }
+    }
+
+    // groups are processed one at a time, so reorder the output by left-hand key
+    let mut left_positions = vec![0; left_order.len()];
+    for (position, &lidx) in left_order.iter().enumerate() {
+        left_positions[lidx] = position;
+    }
+    let mut merged = left_merge_indices
+        .into_iter()
+        .zip(right_merge_indices)
+        .collect::<Vec<_>>();
+    merged.sort_by_key(|&(lidx, _)| left_positions[lidx]);
+    merged.into_iter().unzip()
+}
+
 /// A trait for augmenting type `Store` (a [DataStore](../store/struct.DataStore.html)) with
 /// fields from this [ViewFrameCons](../view/type.ViewFrameCons.html) as labeled by `Labels`.
 pub trait JoinIntoStore<Labels, Store> {
@@ -539,8 +893,9 @@ mod tests {
         println!("{}", dv_emp);
         println!("{}", dv_dept);
 
-        let joined_dv =
-            dv_emp.join::<Join<emp_table::DeptId, dept_table::DeptId, Equal>, _, _>(&dv_dept);
+        let joined_dv = dv_emp
+            .join::<Join<emp_table::DeptId, dept_table::DeptId, Equal>, _, _>(&dv_dept)
+            .unwrap();
         println!("{}", joined_dv);
         assert_eq!(joined_dv.nrows(), 7);
         assert_eq!(joined_dv.nfields(), 5);
@@ -593,8 +948,9 @@ mod tests {
         println!("{}", dv_emp);
         println!("{}", dv_dept);
 
-        let joined_dv =
-            dv_emp.join::<Join<emp_table::DeptId, dept_table::DeptId, Equal>, _, _>(&dv_dept);
+        let joined_dv = dv_emp
+            .join::<Join<emp_table::DeptId, dept_table::DeptId, Equal>, _, _>(&dv_dept)
+            .unwrap();
         println!("{}", joined_dv);
 
         assert_eq!(joined_dv.nrows(), 4);
@@ -650,8 +1006,9 @@ mod tests {
         let dv_dept = sample_dept_table().into_view();
         println!("{}", dv_emp);
         println!("{}", dv_dept);
-        let joined_dv =
-            dv_emp.join::<Join<emp_table::DeptId, dept_table::DeptId, Equal>, _, _>(&dv_dept);
+        let joined_dv = dv_emp
+            .join::<Join<emp_table::DeptId, dept_table::DeptId, Equal>, _, _>(&dv_dept)
+            .unwrap();
         println!("{}", joined_dv);
         assert_eq!(joined_dv.nrows(), 6);
         assert_eq!(joined_dv.nfields(), 5);
@@ -690,8 +1047,9 @@ mod tests {
 
         dv_dept.filter::<dept_table::DeptId, _>(|val: Value<&u64>| val != valref![1u64]);
         println!("{}", dv_dept);
-        let joined_dv =
-            dv_emp.join::<Join<emp_table::DeptId, dept_table::DeptId, Equal>, _, _>(&dv_dept);
+        let joined_dv = dv_emp
+            .join::<Join<emp_table::DeptId, dept_table::DeptId, Equal>, _, _>(&dv_dept)
+            .unwrap();
         println!("{}", joined_dv);
         assert_eq!(joined_dv.nrows(), 4);
         assert_eq!(joined_dv.nfields(), 5);
@@ -716,7 +1074,8 @@ mod tests {
     namespace![
         @continue(typenum::Add1<::test_utils::dept_table::Table>)
         table dept_rename {
-            RDeptId: u64
+            RDeptId: u64,
+            RDeptName: String
         }
     ];
 
@@ -731,7 +1090,8 @@ mod tests {
         let dv_dept = dv_dept.relabel::<dept_table::DeptId, dept_rename::RDeptId>();
         // also test relabeling
         let joined_dv = dv_emp
-            .join::<Join<emp_table::DeptId, dept_rename::RDeptId, GreaterThan>, _, _>(&dv_dept);
+            .join::<Join<emp_table::DeptId, dept_rename::RDeptId, GreaterThan>, _, _>(&dv_dept)
+            .unwrap();
         println!("{}", joined_dv);
         assert_eq!(joined_dv.nrows(), 7);
         assert_eq!(joined_dv.nfields(), 5);
@@ -743,7 +1103,8 @@ mod tests {
         let dv_emp = sample_emp_table().into_view();
         let dv_dept = dept_table(vec![2], vec!["Sales"]).into_view();
         let joined_dv = dv_emp
-            .join::<Join<emp_table::DeptId, dept_table::DeptId, GreaterThanEqual>, _, _>(&dv_dept);
+            .join::<Join<emp_table::DeptId, dept_table::DeptId, GreaterThanEqual>, _, _>(&dv_dept)
+            .unwrap();
         println!("{}", joined_dv);
         assert_eq!(joined_dv.nrows(), 4);
         assert_eq!(joined_dv.nfields(), 5);
@@ -754,8 +1115,9 @@ mod tests {
         // less than
         let dv_emp = sample_emp_table().into_view();
         let dv_dept = dept_table(vec![2], vec!["Sales"]).into_view();
-        let joined_dv =
-            dv_emp.join::<Join<emp_table::DeptId, dept_table::DeptId, LessThan>, _, _>(&dv_dept);
+        let joined_dv = dv_emp
+            .join::<Join<emp_table::DeptId, dept_table::DeptId, LessThan>, _, _>(&dv_dept)
+            .unwrap();
         println!("{}", joined_dv);
         assert_eq!(joined_dv.nrows(), 3);
         assert_eq!(joined_dv.nfields(), 5);
@@ -767,7 +1129,8 @@ mod tests {
         let dv_emp = sample_emp_table().into_view();
         let dv_dept = dept_table(vec![2], vec!["Sales"]).into_view();
         let joined_dv = dv_emp
-            .join::<Join<emp_table::DeptId, dept_table::DeptId, LessThanEqual>, _, _>(&dv_dept);
+            .join::<Join<emp_table::DeptId, dept_table::DeptId, LessThanEqual>, _, _>(&dv_dept)
+            .unwrap();
         println!("{}", joined_dv);
         assert_eq!(joined_dv.nrows(), 4);
         assert_eq!(joined_dv.nfields(), 5);
@@ -775,4 +1138,430 @@ mod tests {
             assert![*value.unwrap() <= 2];
         }
     }
+
+    #[test]
+    fn join_empty() {
+        // empty right side
+        let dv_emp = sample_emp_table().into_view();
+        let mut dv_dept = sample_dept_table().into_view();
+        dv_dept.filter::<dept_table::DeptId, _>(|_: Value<&u64>| false);
+        assert_eq!(dv_dept.nrows(), 0);
+        let joined_dv = dv_emp
+            .join::<Join<emp_table::DeptId, dept_table::DeptId, Equal>, _, _>(&dv_dept)
+            .unwrap();
+        assert_eq!(joined_dv.nrows(), 0);
+        assert_eq!(joined_dv.nfields(), 5);
+        assert_eq!(
+            joined_dv.fieldnames(),
+            vec!["EmpId", "DeptId", "EmpName", "DeptId", "DeptName"]
+        );
+        assert!(joined_dv
+            .field::<dept_table::DeptName>()
+            .to_vec()
+            .is_empty());
+
+        // empty left side
+        let mut dv_emp = sample_emp_table().into_view();
+        dv_emp.filter::<emp_table::EmpId, _>(|_: Value<&u64>| false);
+        let dv_dept = sample_dept_table().into_view();
+        let joined_dv = dv_emp
+            .join::<Join<emp_table::DeptId, dept_table::DeptId, LessThan>, _, _>(&dv_dept)
+            .unwrap();
+        assert_eq!(joined_dv.nrows(), 0);
+        assert_eq!(joined_dv.nfields(), 5);
+
+        // both sides empty
+        let store: emp_table::Store =
+            emp_table![Vec::<u64>::new(), Vec::<u64>::new(), Vec::<&str>::new()];
+        let dv_emp = store.into_view();
+        let dv_dept = dept_table(vec![], vec![]).into_view();
+        let joined_dv = dv_emp
+            .join::<Join<emp_table::DeptId, dept_table::DeptId, GreaterThanEqual>, _, _>(&dv_dept)
+            .unwrap();
+        assert_eq!(joined_dv.nrows(), 0);
+        assert_eq!(joined_dv.nfields(), 5);
+    }
+
+    #[test]
+    fn join_all_na_keys() {
+        // all-NA keys on the right side
+        let dv_emp = sample_emp_table().into_view();
+        let dv_dept = dept_table_from_field(
+            FieldData::from_field_vec(vec![Value::Na, Value::Na]),
+            vec!["Marketing".to_string(), "Sales".to_string()].into(),
+        )
+        .into_view();
+        let joined_dv = dv_emp
+            .join::<Join<emp_table::DeptId, dept_table::DeptId, Equal>, _, _>(&dv_dept)
+            .unwrap();
+        assert_eq!(joined_dv.nrows(), 0);
+        assert_eq!(joined_dv.nfields(), 5);
+        let joined_dv = dv_emp
+            .join::<Join<emp_table::DeptId, dept_table::DeptId, GreaterThan>, _, _>(&dv_dept)
+            .unwrap();
+        assert_eq!(joined_dv.nrows(), 0);
+
+        // all-NA keys on the left side
+        let store: emp_table::Store = emp_table_from_field![
+            vec![0u64, 2].into(),
+            FieldData::from_field_vec(vec![Value::Na, Value::Na]),
+            vec!["Sally".to_string(), "Jamie".to_string()].into()
+        ];
+        let dv_emp = store.into_view();
+        let dv_dept = sample_dept_table().into_view();
+        let joined_dv = dv_emp
+            .join::<Join<emp_table::DeptId, dept_table::DeptId, Equal>, _, _>(&dv_dept)
+            .unwrap();
+        assert_eq!(joined_dv.nrows(), 0);
+        assert_eq!(joined_dv.nfields(), 5);
+        let joined_dv = dv_emp
+            .join::<Join<emp_table::DeptId, dept_table::DeptId, LessThanEqual>, _, _>(&dv_dept)
+            .unwrap();
+        assert_eq!(joined_dv.nrows(), 0);
+
+        // all-NA keys on both sides
+        let dv_dept = dept_table_from_field(
+            FieldData::from_field_vec(vec![Value::Na]),
+            vec!["Marketing".to_string()].into(),
+        )
+        .into_view();
+        let joined_dv = dv_emp
+            .join::<Join<emp_table::DeptId, dept_table::DeptId, Equal>, _, _>(&dv_dept)
+            .unwrap();
+        assert_eq!(joined_dv.nrows(), 0);
+        assert_eq!(joined_dv.nfields(), 5);
+    }
+
+    #[test]
+    fn join_suffix() {
+        let dv_emp = sample_emp_table().into_view();
+        let dv_dept = sample_dept_table().into_view();
+
+        let joined_dv = dv_emp
+            .join::<Join<emp_table::DeptId, dept_table::DeptId, Equal, AddSuffix>, _, _>(&dv_dept)
+            .unwrap();
+        assert_eq!(joined_dv.nrows(), 7);
+        assert_eq!(
+            joined_dv.fieldnames(),
+            vec![
+                "EmpId",
+                "DeptId",
+                "EmpName",
+                "DeptId_right",
+                "DeptName_right"
+            ]
+        );
+        assert_eq!(
+            joined_dv.field::<emp_table::DeptId>().to_vec(),
+            vec![1u64, 1, 1, 2, 3, 4, 4]
+        );
+        assert_eq!(
+            joined_dv.field::<Suffixed<dept_table::DeptId>>().to_vec(),
+            vec![1u64, 1, 1, 2, 3, 4, 4]
+        );
+        assert_eq!(
+            joined_dv.field::<Suffixed<dept_table::DeptName>>().to_vec(),
+            vec![
+                "Marketing",
+                "Marketing",
+                "Marketing",
+                "Sales",
+                "Manufacturing",
+                "R&D",
+                "R&D"
+            ]
+        );
+
+        // custom suffix
+        #[derive(Debug)]
+        struct Dept;
+        impl Suffix for Dept {
+            const SUFFIX: &'static str = "_dept";
+        }
+        let joined_dv = dv_emp
+            .join::<Join<emp_table::DeptId, dept_table::DeptId, Equal, AddSuffix<Dept>>, _, _>(
+                &dv_dept,
+            )
+            .unwrap();
+        assert_eq!(
+            joined_dv.fieldnames(),
+            vec!["EmpId", "DeptId", "EmpName", "DeptId_dept", "DeptName_dept"]
+        );
+        assert_eq!(
+            joined_dv
+                .field::<Suffixed<dept_table::DeptName, Dept>>()
+                .to_vec(),
+            vec![
+                "Marketing",
+                "Marketing",
+                "Marketing",
+                "Sales",
+                "Manufacturing",
+                "R&D",
+                "R&D"
+            ]
+        );
+    }
+
+    #[test]
+    fn self_join_suffix() {
+        // join employees with all other employees in the same department
+        let dv_emp = sample_emp_table().into_view();
+        let joined_dv = dv_emp
+            .join::<Join<emp_table::DeptId, emp_table::DeptId, Equal, AddSuffix>, _, _>(&dv_emp)
+            .unwrap();
+        assert_eq!(joined_dv.nrows(), 15);
+        assert_eq!(joined_dv.nfields(), 6);
+        assert_eq!(
+            joined_dv.fieldnames(),
+            vec![
+                "EmpId",
+                "DeptId",
+                "EmpName",
+                "EmpId_right",
+                "DeptId_right",
+                "EmpName_right"
+            ]
+        );
+        assert_eq!(
+            joined_dv.field::<emp_table::EmpName>().to_vec(),
+            vec![
+                "Sally", "Sally", "Sally", "Bob", "Bob", "Bob", "Cara", "Cara", "Cara", "Jamie",
+                "Louis", "Louise", "Louise", "Ann", "Ann",
+            ]
+        );
+        assert_eq!(
+            joined_dv.field::<Suffixed<emp_table::EmpName>>().to_vec(),
+            vec![
+                "Sally", "Bob", "Cara", "Sally", "Bob", "Cara", "Sally", "Bob", "Cara", "Jamie",
+                "Louis", "Louise", "Ann", "Louise", "Ann",
+            ]
+        );
+        assert_eq!(
+            joined_dv.field::<emp_table::DeptId>().to_vec(),
+            joined_dv.field::<Suffixed<emp_table::DeptId>>().to_vec()
+        );
+    }
+
+    #[test]
+    fn join_relabel_map() {
+        let dv_emp = sample_emp_table().into_view();
+        let dv_dept = sample_dept_table().into_view();
+
+        let joined_dv = dv_emp
+            .join::<Join<
+                emp_table::DeptId,
+                dept_table::DeptId,
+                Equal,
+                RelabelMap<
+                    dept_table::DeptId,
+                    dept_rename::RDeptId,
+                    RelabelMap<dept_table::DeptName, dept_rename::RDeptName>,
+                >,
+            >, _, _>(&dv_dept)
+            .unwrap();
+        assert_eq!(joined_dv.nrows(), 7);
+        assert_eq!(
+            joined_dv.fieldnames(),
+            vec!["EmpId", "DeptId", "EmpName", "RDeptId", "RDeptName"]
+        );
+        assert_eq!(
+            joined_dv.field::<dept_rename::RDeptId>().to_vec(),
+            vec![1u64, 1, 1, 2, 3, 4, 4]
+        );
+        assert_eq!(
+            joined_dv.field::<dept_rename::RDeptName>().to_vec(),
+            vec![
+                "Marketing",
+                "Marketing",
+                "Marketing",
+                "Sales",
+                "Manufacturing",
+                "R&D",
+                "R&D"
+            ]
+        );
+    }
+
+    namespace![
+        table trades {
+            TradeTime: u64,
+            TradeSym: String,
+            Qty: u64
+        }
+        table quotes {
+            QuoteTime: u64,
+            QuoteSym: String,
+            Bid: u64
+        }
+    ];
+
+    fn trades_table() -> trades::Store {
+        DataStore::<Nil>::empty()
+            .push_back_cloned_from_iter(&[2u64, 5, 9, 10, 15])
+            .push_back_from_iter(["A", "B", "A", "B", "A"].iter().map(|&s| s.to_string()))
+            .push_back_cloned_from_iter(&[100u64, 200, 300, 400, 500])
+    }
+
+    fn quotes_table() -> quotes::Store {
+        DataStore::<Nil>::empty()
+            .push_back_cloned_from_iter(&[1u64, 3, 4, 9, 11])
+            .push_back_from_iter(["A", "B", "A", "B", "A"].iter().map(|&s| s.to_string()))
+            .push_back_cloned_from_iter(&[10u64, 20, 30, 40, 50])
+    }
+
+    #[test]
+    fn asof_join() {
+        let dv_trades = trades_table().into_view();
+        let dv_quotes = quotes_table().into_view();
+
+        // backward
+        let joined_dv = dv_trades
+            .join::<Join<trades::TradeTime, quotes::QuoteTime, AsOf<Backward>>, _, _>(&dv_quotes)
+            .unwrap();
+        assert_eq!(joined_dv.nrows(), 5);
+        assert_eq!(joined_dv.nfields(), 6);
+        assert_eq!(
+            joined_dv.field::<trades::Qty>().to_vec(),
+            vec![100u64, 200, 300, 400, 500]
+        );
+        assert_eq!(
+            joined_dv.field::<quotes::QuoteTime>().to_vec(),
+            vec![1u64, 4, 9, 9, 11]
+        );
+        assert_eq!(
+            joined_dv.field::<quotes::Bid>().to_vec(),
+            vec![10u64, 30, 40, 40, 50]
+        );
+
+        // forward (last trade has no later quote)
+        let joined_dv = dv_trades
+            .join::<Join<trades::TradeTime, quotes::QuoteTime, AsOf<Forward>>, _, _>(&dv_quotes)
+            .unwrap();
+        assert_eq!(joined_dv.nrows(), 4);
+        assert_eq!(
+            joined_dv.field::<trades::Qty>().to_vec(),
+            vec![100u64, 200, 300, 400]
+        );
+        assert_eq!(
+            joined_dv.field::<quotes::Bid>().to_vec(),
+            vec![20u64, 40, 40, 50]
+        );
+    }
+
+    #[test]
+    fn asof_join_by() {
+        let dv_trades = trades_table().into_view();
+        let dv_quotes = quotes_table().into_view();
+
+        let joined_dv = dv_trades
+            .join::<Join<
+                trades::TradeTime,
+                quotes::QuoteTime,
+                AsOf<Backward, By<trades::TradeSym, quotes::QuoteSym>>,
+            >, _, _>(&dv_quotes)
+            .unwrap();
+        assert_eq!(joined_dv.nrows(), 5);
+        assert_eq!(
+            joined_dv.field::<trades::Qty>().to_vec(),
+            vec![100u64, 200, 300, 400, 500]
+        );
+        assert_eq!(
+            joined_dv.field::<quotes::QuoteSym>().to_vec(),
+            vec!["A", "B", "A", "B", "A"]
+        );
+        assert_eq!(
+            joined_dv.field::<quotes::Bid>().to_vec(),
+            vec![10u64, 20, 30, 40, 50]
+        );
+
+        let joined_dv = dv_trades
+            .join::<Join<
+                trades::TradeTime,
+                quotes::QuoteTime,
+                AsOf<Forward, By<trades::TradeSym, quotes::QuoteSym>>,
+            >, _, _>(&dv_quotes)
+            .unwrap();
+        assert_eq!(
+            joined_dv.field::<trades::Qty>().to_vec(),
+            vec![100u64, 200, 300]
+        );
+        assert_eq!(
+            joined_dv.field::<quotes::Bid>().to_vec(),
+            vec![30u64, 40, 50]
+        );
+    }
+
+    #[test]
+    fn asof_join_tolerance() {
+        let dv_trades = trades_table().into_view();
+        let dv_quotes = quotes_table().into_view();
+
+        let joined_dv = dv_trades
+            .join_asof::<Join<
+                trades::TradeTime,
+                quotes::QuoteTime,
+                AsOf<Backward, By<trades::TradeSym, quotes::QuoteSym>>,
+            >, _, _>(&dv_quotes, Some(2))
+            .unwrap();
+        assert_eq!(joined_dv.nrows(), 3);
+        assert_eq!(
+            joined_dv.field::<trades::Qty>().to_vec(),
+            vec![100u64, 200, 400]
+        );
+        assert_eq!(
+            joined_dv.field::<quotes::Bid>().to_vec(),
+            vec![10u64, 20, 40]
+        );
+
+        let joined_dv = dv_trades
+            .join_asof::<Join<trades::TradeTime, quotes::QuoteTime, AsOf<Forward>>, _, _>(
+                &dv_quotes,
+                Some(0),
+            )
+            .unwrap();
+        assert_eq!(joined_dv.nrows(), 1);
+        assert_eq!(joined_dv.field::<trades::Qty>().to_vec(), vec![300u64]);
+        assert_eq!(joined_dv.field::<quotes::Bid>().to_vec(), vec![40u64]);
+    }
+
+    #[test]
+    fn asof_join_unsorted_na() {
+        let dv_trades = {
+            let store: trades::Store = DataStore::<Nil>::empty()
+                .push_back_field(FieldData::from_field_vec(vec![
+                    Value::Exists(10u64),
+                    Value::Na,
+                    Value::Exists(2),
+                ]))
+                .push_back_from_iter(["A", "A", "A"].iter().map(|&s| s.to_string()))
+                .push_back_cloned_from_iter(&[100u64, 200, 300]);
+            store.into_view()
+        };
+        let dv_quotes = {
+            let store: quotes::Store = DataStore::<Nil>::empty()
+                .push_back_field(FieldData::from_field_vec(vec![
+                    Value::Na,
+                    Value::Exists(9u64),
+                    Value::Exists(1),
+                    Value::Exists(9),
+                ]))
+                .push_back_from_iter(["A", "A", "A", "A"].iter().map(|&s| s.to_string()))
+                .push_back_cloned_from_iter(&[10u64, 20, 30, 40]);
+            store.into_view()
+        };
+
+        // NA keys never match; ties choose last record for backward joins, first for forward
+        let joined_dv = dv_trades
+            .join::<Join<trades::TradeTime, quotes::QuoteTime, AsOf<Backward>>, _, _>(&dv_quotes)
+            .unwrap();
+        assert_eq!(joined_dv.field::<trades::Qty>().to_vec(), vec![300u64, 100]);
+        assert_eq!(joined_dv.field::<quotes::Bid>().to_vec(), vec![30u64, 40]);
+
+        let joined_dv = dv_trades
+            .join::<Join<trades::TradeTime, quotes::QuoteTime, AsOf<Forward>>, _, _>(&dv_quotes)
+            .unwrap();
+        assert_eq!(joined_dv.field::<trades::Qty>().to_vec(), vec![300u64]);
+        assert_eq!(joined_dv.field::<quotes::Bid>().to_vec(), vec![20u64]);
+    }
 }
@@ -1,6 +1,7 @@
 /*!
 Traits, structs, and type aliases for handling labels and associated logic.
 */
+use std::borrow::Cow;
 use std::collections::VecDeque;
 use std::marker::PhantomData;
 use std::ops::{Add, BitAnd, BitOr, Not, Sub};
@@ -70,8 +71,9 @@ impl<U, B> Identifier for UInt<U, B> {
 
 /// Trait to access name and type description for a label.
 pub trait LabelName {
-    /// Returns the label name.
-    fn name() -> &'static str;
+    /// Returns the label name. Names of derived labels (e.g. [Suffixed](struct.Suffixed.html)) are
+    /// built when requested.
+    fn name() -> Cow<'static, str>;
     /// Returns a string specified the type of this data referred to by this label.
     fn str_type() -> &'static str;
 }
@@ -79,8 +81,8 @@ impl<T> LabelName for T
 where
     T: Label,
 {
-    fn name() -> &'static str {
-        T::NAME
+    fn name() -> Cow<'static, str> {
+        Cow::Borrowed(T::NAME)
     }
     fn str_type() -> &'static str {
         T::TYPE
@@ -144,6 +146,87 @@ where
     type Eq = <T::Ident as IdentEq<U::Ident>>::Eq;
 }
 
+/// Trait for a suffix which can be appended to label names using the
+/// [Suffixed](struct.Suffixed.html) label.
+pub trait Suffix {
+    /// The string to append to the label name.
+    const SUFFIX: &'static str;
+}
+
+/// Suffix for labels from the right-hand side of a join (`"_right"`).
+#[derive(Debug, Clone)]
+pub struct RightSuffix;
+impl Suffix for RightSuffix {
+    const SUFFIX: &'static str = "_right";
+}
+
+/// A label derived from the label `L` with the suffix `S` appended to its name (e.g.
+/// `Suffixed<gdp::Year1983, RightSuffix>` is named "Year1983_right"). A `Suffixed` label refers to
+/// a different field than `L`.
+#[derive(Debug, Clone)]
+pub struct Suffixed<L, S = RightSuffix> {
+    _marker: PhantomData<(L, S)>,
+}
+
+/// Identifier for a [Suffixed](struct.Suffixed.html) label, composed of the identifier `I` of the
+/// original label and the suffix `S`.
+#[derive(Debug, Clone)]
+pub struct SuffixedIdent<I, S> {
+    _marker: PhantomData<(I, S)>,
+}
+
+impl<I, S> Identifier for SuffixedIdent<I, S>
+where
+    I: Identifier,
+{
+    type Ident = Self;
+    type Table = I::Table;
+    type Natural = I::Natural;
+}
+impl<L, S> Identifier for Suffixed<L, S>
+where
+    L: Identifier,
+{
+    type Ident = SuffixedIdent<L::Ident, S>;
+    type Table = L::Table;
+    type Natural = L::Natural;
+}
+
+// A suffixed identifier never matches an unsuffixed identifier, and matches another suffixed
+// identifier (with the same suffix) if the original identifiers match.
+impl<I, S, UTbl, UNat> IdentEq<Ident<UTbl, UNat>> for SuffixedIdent<I, S> {
+    type Eq = False;
+}
+impl<TTbl, TNat, I, S> IdentEq<SuffixedIdent<I, S>> for Ident<TTbl, TNat> {
+    type Eq = False;
+}
+impl<I, J, S> IdentEq<SuffixedIdent<J, S>> for SuffixedIdent<I, S>
+where
+    I: IdentEq<J>,
+{
+    type Eq = <I as IdentEq<J>>::Eq;
+}
+
+impl<L, S> LabelName for Suffixed<L, S>
+where
+    L: LabelName,
+    S: Suffix,
+{
+    fn name() -> Cow<'static, str> {
+        Cow::Owned(format!("{}{}", L::name(), S::SUFFIX))
+    }
+    fn str_type() -> &'static str {
+        L::str_type()
+    }
+}
+
+impl<L, S> Typed for Suffixed<L, S>
+where
+    L: Typed,
+{
+    type DType = L::DType;
+}
+
 /// Container for a value of type `V` labeled with `L`.
 #[derive(Debug, Clone)]
 pub struct Labeled<L, V> {
@@ -712,10 +795,10 @@ impl AssocLabels for Nil {
 /// the `Self` cons-list.
 pub trait StrLabels {
     /// Returns the labels (as strings) for the labels associated with `Self`.
-    fn labels<'a>() -> VecDeque<&'a str>;
+    fn labels() -> VecDeque<Cow<'static, str>>;
 }
 impl StrLabels for Nil {
-    fn labels<'a>() -> VecDeque<&'a str> {
+    fn labels() -> VecDeque<Cow<'static, str>> {
         VecDeque::new()
     }
 }
@@ -724,7 +807,7 @@ where
     L: LabelName,
     T: StrLabels,
 {
-    fn labels<'a>() -> VecDeque<&'a str> {
+    fn labels() -> VecDeque<Cow<'static, str>> {
         let mut previous = T::labels();
         previous.push_front(L::name());
         previous
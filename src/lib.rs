@@ -15,6 +15,9 @@ extern crate hyper_tls;
 extern crate indexmap;
 extern crate native_tls;
 extern crate num_traits;
+extern crate regex;
+#[cfg(feature = "parallel")]
+extern crate rayon;
 extern crate serde;
 extern crate tokio_core;
 extern crate tokio_io;
@@ -29,8 +32,6 @@ extern crate rand;
 #[cfg(test)]
 extern crate serde_json;
 
-#[macro_use]
-pub mod ops;
 #[macro_use]
 pub mod cons;
 #[macro_use]
@@ -48,16 +49,22 @@ pub mod field;
 #[macro_use]
 pub mod test_utils;
 
+#[macro_use]
+pub mod ops;
+
 pub mod access;
+pub mod cast;
 pub mod error;
 pub mod frame;
 pub mod join;
+pub mod par;
+#[macro_use]
+pub mod reshape;
 pub mod select;
 pub mod source;
 pub mod stats;
 pub mod view;
 pub mod view_stats;
-// pub mod reshape;
 
 #[cfg(feature = "experimental")]
 pub mod experimental;
@@ -0,0 +1,301 @@
+/*!
+Implementation of boolean logic operations (`BitAnd`, `BitOr`, `BitXor`, `Not`) for boolean fields,
+such as the masks produced by element-wise comparisons.
+
+Missing values follow three-valued (Kleene) logic, as in SQL: a missing value is treated as an
+unknown boolean, so `NA & false` is `false` and `NA | true` is `true`, while `NA & true`,
+`NA | false`, `NA ^ x` and `!NA` are all missing.
+
+The operators panic if the two fields are of different lengths; the methods of
+[LengthCheckedLogic](trait.LengthCheckedLogic.html) instead return a `LengthMismatch` error.
+*/
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+
+use access::DataIndex;
+use error;
+use field::{FieldData, Value};
+use frame::Framed;
+use store::DataRef;
+
+fn kleene_and(left: Value<&bool>, right: Value<&bool>) -> Value<bool> {
+    match (left, right) {
+        (Value::Exists(&false), _) | (_, Value::Exists(&false)) => Value::Exists(false),
+        (Value::Exists(&true), Value::Exists(&true)) => Value::Exists(true),
+        _ => Value::Na,
+    }
+}
+
+fn kleene_or(left: Value<&bool>, right: Value<&bool>) -> Value<bool> {
+    match (left, right) {
+        (Value::Exists(&true), _) | (_, Value::Exists(&true)) => Value::Exists(true),
+        (Value::Exists(&false), Value::Exists(&false)) => Value::Exists(false),
+        _ => Value::Na,
+    }
+}
+
+fn kleene_xor(left: Value<&bool>, right: Value<&bool>) -> Value<bool> {
+    match (left, right) {
+        (Value::Exists(left), Value::Exists(right)) => Value::Exists(left ^ right),
+        _ => Value::Na,
+    }
+}
+
+fn zip_logic<L, R, F>(left: &L, right: &R, logic_fn: F) -> FieldData<bool>
+where
+    L: DataIndex<DType = bool>,
+    R: DataIndex<DType = bool>,
+    F: Fn(Value<&bool>, Value<&bool>) -> Value<bool>,
+{
+    assert_eq!(
+        left.len(),
+        right.len(),
+        "boolean operation between fields of different lengths"
+    );
+    left.iter()
+        .zip(right.iter())
+        .map(|(l, r)| logic_fn(l, r))
+        .collect()
+}
+
+fn zip_logic_checked<L, R, F>(left: &L, right: &R, logic_fn: F) -> error::Result<FieldData<bool>>
+where
+    L: DataIndex<DType = bool>,
+    R: DataIndex<DType = bool>,
+    F: Fn(Value<&bool>, Value<&bool>) -> Value<bool>,
+{
+    if left.len() != right.len() {
+        Err(error::AgnesError::LengthMismatch {
+            expected: left.len(),
+            actual: right.len(),
+        })
+    } else {
+        Ok(zip_logic(left, right, logic_fn))
+    }
+}
+
+/// A trait for boolean logic operations between two boolean fields that return an error if the
+/// fields are of different lengths (instead of panicking, as the `&`, `|` and `^` operators do).
+pub trait LengthCheckedLogic<Rhs> {
+    /// Returns the logical AND of this field and `rhs`, returning an error if the fields are of
+    /// different lengths.
+    fn and_checked(&self, rhs: &Rhs) -> error::Result<FieldData<bool>>;
+    /// Returns the logical OR of this field and `rhs`, returning an error if the fields are of
+    /// different lengths.
+    fn or_checked(&self, rhs: &Rhs) -> error::Result<FieldData<bool>>;
+    /// Returns the logical XOR of this field and `rhs`, returning an error if the fields are of
+    /// different lengths.
+    fn xor_checked(&self, rhs: &Rhs) -> error::Result<FieldData<bool>>;
+}
+
+macro_rules! impl_bool_op {
+    (
+        $trait_name:tt $trait_fn:tt $logic_fn:tt;
+        $([[$($lty_tt:tt)*] [$($rty_tt:tt)*]])*
+    ) => {$(
+
+        // &left <op> &right
+        impl<'a, 'b> $trait_name<&'b $($rty_tt)*> for &'a $($lty_tt)* {
+            type Output = FieldData<bool>;
+
+            fn $trait_fn(self, rhs: &'b $($rty_tt)*) -> FieldData<bool> {
+                zip_logic(self, rhs, $logic_fn)
+            }
+        }
+
+        // &left <op> right
+        impl<'a> $trait_name<$($rty_tt)*> for &'a $($lty_tt)* {
+            type Output = FieldData<bool>;
+
+            fn $trait_fn(self, rhs: $($rty_tt)*) -> FieldData<bool> {
+                self.$trait_fn(&rhs)
+            }
+        }
+
+        // left <op> &right
+        impl<'b> $trait_name<&'b $($rty_tt)*> for $($lty_tt)* {
+            type Output = FieldData<bool>;
+
+            fn $trait_fn(self, rhs: &'b $($rty_tt)*) -> FieldData<bool> {
+                (&self).$trait_fn(rhs)
+            }
+        }
+
+        // left <op> right
+        impl $trait_name<$($rty_tt)*> for $($lty_tt)* {
+            type Output = FieldData<bool>;
+
+            fn $trait_fn(self, rhs: $($rty_tt)*) -> FieldData<bool> {
+                (&self).$trait_fn(&rhs)
+            }
+        }
+
+    )*}
+}
+
+macro_rules! impl_bool_ops {
+    ($($trait_name:tt $trait_fn:tt $logic_fn:tt;)*) => {$(
+        impl_bool_op![
+            $trait_name $trait_fn $logic_fn;
+
+            [[FieldData<bool>] [FieldData<bool>]]
+            [[FieldData<bool>] [Framed<bool>]]
+            [[FieldData<bool>] [DataRef<bool>]]
+
+            [[Framed<bool>] [FieldData<bool>]]
+            [[Framed<bool>] [Framed<bool>]]
+            [[Framed<bool>] [DataRef<bool>]]
+
+            [[DataRef<bool>] [FieldData<bool>]]
+            [[DataRef<bool>] [Framed<bool>]]
+            [[DataRef<bool>] [DataRef<bool>]]
+        ];
+    )*}
+}
+
+impl_bool_ops![
+    BitAnd bitand kleene_and;
+    BitOr bitor kleene_or;
+    BitXor bitxor kleene_xor;
+];
+
+macro_rules! impl_checked_logic {
+    ($([[$($lty_tt:tt)*] [$($rty_tt:tt)*]])*) => {$(
+        impl LengthCheckedLogic<$($rty_tt)*> for $($lty_tt)* {
+            fn and_checked(&self, rhs: &$($rty_tt)*) -> error::Result<FieldData<bool>> {
+                zip_logic_checked(self, rhs, kleene_and)
+            }
+            fn or_checked(&self, rhs: &$($rty_tt)*) -> error::Result<FieldData<bool>> {
+                zip_logic_checked(self, rhs, kleene_or)
+            }
+            fn xor_checked(&self, rhs: &$($rty_tt)*) -> error::Result<FieldData<bool>> {
+                zip_logic_checked(self, rhs, kleene_xor)
+            }
+        }
+    )*}
+}
+
+impl_checked_logic![
+    [[FieldData<bool>] [FieldData<bool>]]
+    [[FieldData<bool>] [Framed<bool>]]
+    [[FieldData<bool>] [DataRef<bool>]]
+
+    [[Framed<bool>] [FieldData<bool>]]
+    [[Framed<bool>] [Framed<bool>]]
+    [[Framed<bool>] [DataRef<bool>]]
+
+    [[DataRef<bool>] [FieldData<bool>]]
+    [[DataRef<bool>] [Framed<bool>]]
+    [[DataRef<bool>] [DataRef<bool>]]
+];
+
+macro_rules! impl_bool_not {
+    ($([$($ty_tt:tt)*])*) => {$(
+
+        // !&data
+        impl<'a> Not for &'a $($ty_tt)* {
+            type Output = FieldData<bool>;
+
+            fn not(self) -> FieldData<bool> {
+                self.iter().map(|value| value.map(|value| !value)).collect()
+            }
+        }
+
+        // !data
+        impl Not for $($ty_tt)* {
+            type Output = FieldData<bool>;
+
+            fn not(self) -> FieldData<bool> {
+                !&self
+            }
+        }
+
+    )*}
+}
+
+impl_bool_not![[FieldData<bool>] [Framed<bool>] [DataRef<bool>]];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn truth_table() -> (FieldData<bool>, FieldData<bool>) {
+        let t = Value::Exists(true);
+        let f = Value::Exists(false);
+        let na = Value::Na;
+        (
+            FieldData::from_field_vec(vec![t, t, t, f, f, f, na, na, na]),
+            FieldData::from_field_vec(vec![t, f, na, t, f, na, t, f, na]),
+        )
+    }
+
+    #[test]
+    fn and_or_xor() {
+        let (left, right) = truth_table();
+        let t = Value::Exists(true);
+        let f = Value::Exists(false);
+        let na = Value::Na;
+
+        assert_eq!(
+            (&left & &right).to_value_vec(),
+            vec![t, f, na, f, f, f, na, f, na]
+        );
+        assert_eq!(
+            (&left | &right).to_value_vec(),
+            vec![t, t, t, t, f, na, t, na, na]
+        );
+        assert_eq!(
+            (&left ^ &right).to_value_vec(),
+            vec![f, t, na, t, f, na, na, na, na]
+        );
+
+        // results are the same for all combinations of field types and references
+        let expected = &left & &right;
+        let left_framed: Framed<bool> = left.clone().into();
+        let right_ref: DataRef<bool> = right.clone().into();
+        assert_eq!(&left_framed & &right_ref, expected);
+        assert_eq!(&right_ref & &left_framed, expected);
+        assert_eq!(left_framed.clone() & &right, expected);
+        assert_eq!(&left & right_ref.clone(), expected);
+        assert_eq!(left.clone() & right.clone(), expected);
+        assert_eq!(left_framed | right_ref, &left | &right);
+    }
+
+    #[test]
+    fn length_checked() {
+        let (left, right) = truth_table();
+        assert_eq!(left.and_checked(&right).unwrap(), &left & &right);
+        assert_eq!(left.or_checked(&right).unwrap(), &left | &right);
+        let right_ref: DataRef<bool> = right.clone().into();
+        assert_eq!(left.xor_checked(&right_ref).unwrap(), &left ^ &right);
+
+        let short = FieldData::<bool>::from_vec(vec![true, false]);
+        match left.and_checked(&short) {
+            Err(error::AgnesError::LengthMismatch { expected, actual }) => {
+                assert_eq!(expected, 9);
+                assert_eq!(actual, 2);
+            }
+            _ => panic!("expected LengthMismatch error"),
+        }
+        assert!(short.or_checked(&right_ref).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "boolean operation between fields of different lengths")]
+    fn length_mismatch_panics() {
+        let (left, _) = truth_table();
+        let _ = &left | &FieldData::<bool>::from_vec(vec![true]);
+    }
+
+    #[test]
+    fn not() {
+        let field =
+            FieldData::from_field_vec(vec![Value::Exists(true), Value::Na, Value::Exists(false)]);
+        let expected = vec![Value::Exists(false), Value::Na, Value::Exists(true)];
+        assert_eq!((!&field).to_value_vec(), expected);
+        let data_ref: DataRef<bool> = field.clone().into();
+        assert_eq!((!&data_ref).to_value_vec(), expected);
+        let framed: Framed<bool> = field.clone().into();
+        assert_eq!((!framed).to_value_vec(), expected);
+        assert_eq!(!!field.clone(), field);
+    }
+}
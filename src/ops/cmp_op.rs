@@ -0,0 +1,308 @@
+/*!
+Element-wise comparison operations (equal, not equal, less than, less than or equal, greater than,
+greater than or equal) between a field and a scalar or between two fields, producing boolean
+mask fields. A comparison involving a missing value produces a missing value. Comparisons between
+two fields return a `LengthMismatch` error if the fields are of different lengths.
+*/
+use access::DataIndex;
+use error;
+use field::{FieldData, Value};
+use frame::Framed;
+use store::DataRef;
+
+/// A trait for element-wise comparisons of this field with `Rhs` (either a scalar or another
+/// field), producing a boolean field. Comparisons with a missing value result in a missing value.
+/// Comparisons with a scalar produce a `FieldData<bool>`; comparisons with another field produce
+/// an `error::Result<FieldData<bool>>`, which is a `LengthMismatch` error if the fields are of
+/// different lengths.
+pub trait ElemCmp<Rhs> {
+    /// The data type of the values being compared.
+    type DType;
+    /// The result of a comparison.
+    type Output;
+
+    /// Returns a boolean field containing the result of calling `compare` with each of this
+    /// field's values and `rhs` (or the corresponding value of `rhs`, if `rhs` is a field).
+    fn elem_cmp_by<F>(&self, rhs: Rhs, compare: F) -> Self::Output
+    where
+        F: Fn(&Self::DType, &Self::DType) -> bool;
+
+    /// Returns a boolean field which is `true` where this field's values are equal to `rhs`.
+    fn elem_eq(&self, rhs: Rhs) -> Self::Output
+    where
+        Self::DType: PartialEq,
+    {
+        self.elem_cmp_by(rhs, |left, right| left == right)
+    }
+    /// Returns a boolean field which is `true` where this field's values are not equal to `rhs`.
+    fn elem_ne(&self, rhs: Rhs) -> Self::Output
+    where
+        Self::DType: PartialEq,
+    {
+        self.elem_cmp_by(rhs, |left, right| left != right)
+    }
+    /// Returns a boolean field which is `true` where this field's values are less than `rhs`.
+    fn elem_lt(&self, rhs: Rhs) -> Self::Output
+    where
+        Self::DType: PartialOrd,
+    {
+        self.elem_cmp_by(rhs, |left, right| left < right)
+    }
+    /// Returns a boolean field which is `true` where this field's values are less than or equal
+    /// to `rhs`.
+    fn elem_le(&self, rhs: Rhs) -> Self::Output
+    where
+        Self::DType: PartialOrd,
+    {
+        self.elem_cmp_by(rhs, |left, right| left <= right)
+    }
+    /// Returns a boolean field which is `true` where this field's values are greater than `rhs`.
+    fn elem_gt(&self, rhs: Rhs) -> Self::Output
+    where
+        Self::DType: PartialOrd,
+    {
+        self.elem_cmp_by(rhs, |left, right| left > right)
+    }
+    /// Returns a boolean field which is `true` where this field's values are greater than or
+    /// equal to `rhs`.
+    fn elem_ge(&self, rhs: Rhs) -> Self::Output
+    where
+        Self::DType: PartialOrd,
+    {
+        self.elem_cmp_by(rhs, |left, right| left >= right)
+    }
+}
+
+macro_rules! impl_scalar_cmp {
+    ($([$($ty_tt:tt)*])*) => {$(
+
+        // data <cmp> &scalar
+        impl<'b, T> ElemCmp<&'b T> for $($ty_tt)*
+        where
+            $($ty_tt)*: DataIndex<DType = T>,
+        {
+            type DType = T;
+            type Output = FieldData<bool>;
+
+            fn elem_cmp_by<F>(&self, rhs: &'b T, compare: F) -> FieldData<bool>
+            where
+                F: Fn(&T, &T) -> bool,
+            {
+                self.iter().map(|value| value.map(|value| compare(value, rhs))).collect()
+            }
+        }
+
+        // data <cmp> scalar
+        impl<T> ElemCmp<T> for $($ty_tt)*
+        where
+            $($ty_tt)*: DataIndex<DType = T>,
+        {
+            type DType = T;
+            type Output = FieldData<bool>;
+
+            fn elem_cmp_by<F>(&self, rhs: T, compare: F) -> FieldData<bool>
+            where
+                F: Fn(&T, &T) -> bool,
+            {
+                self.elem_cmp_by(&rhs, compare)
+            }
+        }
+
+    )*}
+}
+
+impl_scalar_cmp![[FieldData<T>] [Framed<T>] [DataRef<T>]];
+
+macro_rules! impl_field_cmp {
+    ($([[$($lty_tt:tt)*] [$($rty_tt:tt)*]])*) => {$(
+
+        // data <cmp> &data
+        impl<'b, T> ElemCmp<&'b $($rty_tt)*> for $($lty_tt)*
+        where
+            $($lty_tt)*: DataIndex<DType = T>,
+            $($rty_tt)*: DataIndex<DType = T>,
+        {
+            type DType = T;
+            type Output = error::Result<FieldData<bool>>;
+
+            fn elem_cmp_by<F>(
+                &self,
+                rhs: &'b $($rty_tt)*,
+                compare: F,
+            ) -> error::Result<FieldData<bool>>
+            where
+                F: Fn(&T, &T) -> bool,
+            {
+                if self.len() != rhs.len() {
+                    return Err(error::AgnesError::LengthMismatch {
+                        expected: self.len(),
+                        actual: rhs.len(),
+                    });
+                }
+                Ok(self.iter()
+                    .zip(rhs.iter())
+                    .map(|values| match values {
+                        (Value::Exists(left), Value::Exists(right)) => {
+                            Value::Exists(compare(left, right))
+                        }
+                        _ => Value::Na,
+                    })
+                    .collect())
+            }
+        }
+
+    )*}
+}
+
+impl_field_cmp![
+    [[FieldData<T>] [FieldData<T>]]
+    [[FieldData<T>] [Framed<T>]]
+    [[FieldData<T>] [DataRef<T>]]
+
+    [[Framed<T>] [FieldData<T>]]
+    [[Framed<T>] [Framed<T>]]
+    [[Framed<T>] [DataRef<T>]]
+
+    [[DataRef<T>] [FieldData<T>]]
+    [[DataRef<T>] [Framed<T>]]
+    [[DataRef<T>] [DataRef<T>]]
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(field: &FieldData<bool>) -> Vec<Value<bool>> {
+        field.to_value_vec()
+    }
+
+    #[test]
+    fn scalar_cmp() {
+        let field = FieldData::from_field_vec(vec![
+            Value::Exists(1.5f64),
+            Value::Na,
+            Value::Exists(3.0),
+            Value::Exists(4.5),
+        ]);
+        let na = Value::Na;
+        assert_eq!(
+            values(&field.elem_gt(3.0)),
+            vec![
+                Value::Exists(false),
+                na,
+                Value::Exists(false),
+                Value::Exists(true)
+            ]
+        );
+        assert_eq!(
+            values(&field.elem_ge(&3.0)),
+            vec![
+                Value::Exists(false),
+                na,
+                Value::Exists(true),
+                Value::Exists(true)
+            ]
+        );
+        assert_eq!(
+            values(&field.elem_lt(3.0)),
+            vec![
+                Value::Exists(true),
+                na,
+                Value::Exists(false),
+                Value::Exists(false)
+            ]
+        );
+        assert_eq!(
+            values(&field.elem_le(3.0)),
+            vec![
+                Value::Exists(true),
+                na,
+                Value::Exists(true),
+                Value::Exists(false)
+            ]
+        );
+        assert_eq!(
+            values(&field.elem_eq(3.0)),
+            vec![
+                Value::Exists(false),
+                na,
+                Value::Exists(true),
+                Value::Exists(false)
+            ]
+        );
+        assert_eq!(
+            values(&field.elem_ne(3.0)),
+            vec![
+                Value::Exists(true),
+                na,
+                Value::Exists(false),
+                Value::Exists(true)
+            ]
+        );
+
+        let framed: Framed<f64> = field.clone().into();
+        assert_eq!(framed.elem_gt(3.0), field.elem_gt(3.0));
+        let data_ref: DataRef<f64> = field.clone().into();
+        assert_eq!(data_ref.elem_le(&1.5), field.elem_le(1.5));
+
+        let names = FieldData::from_vec(vec!["Bob".to_string(), "Sally".to_string()]);
+        assert_eq!(
+            names.elem_eq("Sally".to_string()).to_vec(),
+            vec![false, true]
+        );
+    }
+
+    #[test]
+    fn field_cmp() {
+        let left = FieldData::from_field_vec(vec![
+            Value::Exists(1u64),
+            Value::Exists(5),
+            Value::Na,
+            Value::Exists(3),
+        ]);
+        let right = FieldData::from_field_vec(vec![
+            Value::Exists(2u64),
+            Value::Exists(5),
+            Value::Exists(1),
+            Value::Na,
+        ]);
+        let na = Value::Na;
+        assert_eq!(
+            values(&left.elem_eq(&right).unwrap()),
+            vec![Value::Exists(false), Value::Exists(true), na, na]
+        );
+        assert_eq!(
+            values(&left.elem_ne(&right).unwrap()),
+            vec![Value::Exists(true), Value::Exists(false), na, na]
+        );
+        assert_eq!(
+            values(&left.elem_lt(&right).unwrap()),
+            vec![Value::Exists(true), Value::Exists(false), na, na]
+        );
+        assert_eq!(
+            values(&left.elem_ge(&right).unwrap()),
+            vec![Value::Exists(false), Value::Exists(true), na, na]
+        );
+
+        let left_framed: Framed<u64> = left.clone().into();
+        let right_ref: DataRef<u64> = right.clone().into();
+        assert_eq!(
+            left_framed.elem_gt(&right_ref).unwrap(),
+            left.elem_gt(&right).unwrap()
+        );
+        assert_eq!(
+            right_ref.elem_le(&left_framed).unwrap(),
+            right.elem_le(&left).unwrap()
+        );
+
+        let short = FieldData::<u64>::from_vec(vec![1u64, 5, 2]);
+        match left.elem_eq(&short) {
+            Err(error::AgnesError::LengthMismatch { expected, actual }) => {
+                assert_eq!(expected, 4);
+                assert_eq!(actual, 3);
+            }
+            _ => panic!("expected LengthMismatch error"),
+        }
+        assert!(short.elem_lt(&left_framed).is_err());
+    }
+}
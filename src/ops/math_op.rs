@@ -0,0 +1,254 @@
+/*!
+Element-wise mathematical functions (absolute value, powers, roots, logarithms, rounding and
+clipping) for numeric fields. Each function returns a new `FieldData` containing the result for
+each value of the original field; missing values remain missing.
+*/
+use std::fmt::Debug;
+use std::ops::Mul;
+
+use num_traits::{pow, Float, One, Signed};
+
+use access::DataIndex;
+use field::FieldData;
+
+fn map_exists<DI, F>(data: &DI, f: F) -> FieldData<DI::DType>
+where
+    DI: DataIndex,
+    DI::DType: Debug + Default,
+    F: Fn(&DI::DType) -> DI::DType,
+{
+    data.iter().map(|value| value.map(&f)).collect()
+}
+
+/// Extension trait providing element-wise mathematical functions for numeric fields. Functions
+/// which are only meaningful for floating-point data (e.g. `sqrt`, `ln`, `round`) are available
+/// for `f32` and `f64` fields; `abs`, `pow` and `clip` are also available for integer fields.
+pub trait FieldMath: DataIndex + Sized {
+    /// Returns a field containing the absolute value of each value in this field.
+    fn abs(&self) -> FieldData<Self::DType>
+    where
+        Self::DType: Signed + Debug + Default,
+    {
+        map_exists(self, Signed::abs)
+    }
+    /// Returns a field containing each value in this field raised to the integer power `exp`.
+    fn pow(&self, exp: usize) -> FieldData<Self::DType>
+    where
+        Self::DType: Clone + One + Mul<Output = Self::DType> + Debug + Default,
+    {
+        map_exists(self, |value| pow(value.clone(), exp))
+    }
+    /// Returns a field containing each value in this field raised to the floating-point power
+    /// `exp`.
+    fn powf(&self, exp: Self::DType) -> FieldData<Self::DType>
+    where
+        Self::DType: Float + Debug + Default,
+    {
+        map_exists(self, |value| value.powf(exp))
+    }
+    /// Returns a field containing the square root of each value in this field. Negative values
+    /// result in `NaN`.
+    fn sqrt(&self) -> FieldData<Self::DType>
+    where
+        Self::DType: Float + Debug + Default,
+    {
+        map_exists(self, |value| value.sqrt())
+    }
+    /// Returns a field containing the natural logarithm of each value in this field. Negative
+    /// values result in `NaN`, and zero values in negative infinity.
+    fn ln(&self) -> FieldData<Self::DType>
+    where
+        Self::DType: Float + Debug + Default,
+    {
+        map_exists(self, |value| value.ln())
+    }
+    /// Returns a field containing `e` raised to the power of each value in this field.
+    fn exp(&self) -> FieldData<Self::DType>
+    where
+        Self::DType: Float + Debug + Default,
+    {
+        map_exists(self, |value| value.exp())
+    }
+    /// Returns a field containing each value in this field rounded to the nearest integer, with
+    /// half-way cases rounded away from zero.
+    fn round(&self) -> FieldData<Self::DType>
+    where
+        Self::DType: Float + Debug + Default,
+    {
+        map_exists(self, |value| value.round())
+    }
+    /// Returns a field containing the largest integer less than or equal to each value in this
+    /// field.
+    fn floor(&self) -> FieldData<Self::DType>
+    where
+        Self::DType: Float + Debug + Default,
+    {
+        map_exists(self, |value| value.floor())
+    }
+    /// Returns a field containing the smallest integer greater than or equal to each value in this
+    /// field.
+    fn ceil(&self) -> FieldData<Self::DType>
+    where
+        Self::DType: Float + Debug + Default,
+    {
+        map_exists(self, |value| value.ceil())
+    }
+    /// Returns a field containing each value in this field clipped to the range `[lower, upper]`:
+    /// values less than `lower` become `lower`, and values greater than `upper` become `upper`.
+    fn clip(&self, lower: Self::DType, upper: Self::DType) -> FieldData<Self::DType>
+    where
+        Self::DType: PartialOrd + Clone + Debug + Default,
+    {
+        map_exists(self, |value| {
+            if *value < lower {
+                lower.clone()
+            } else if *value > upper {
+                upper.clone()
+            } else {
+                value.clone()
+            }
+        })
+    }
+}
+
+impl<DI> FieldMath for DI where DI: DataIndex {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use field::Value;
+
+    fn approx_eq(left: &[Value<f64>], right: &[Value<f64>]) -> bool {
+        left.len() == right.len()
+            && left.iter().zip(right.iter()).all(|values| match values {
+                (&Value::Exists(left), &Value::Exists(right)) => {
+                    (left - right).abs() < 1e-9 || (left.is_nan() && right.is_nan())
+                }
+                (&Value::Na, &Value::Na) => true,
+                _ => false,
+            })
+    }
+
+    #[test]
+    fn float_math() {
+        let field = FieldData::from_field_vec(vec![
+            Value::Exists(1.0f64),
+            Value::Na,
+            Value::Exists(4.0),
+            Value::Exists(-2.5),
+        ]);
+        let na = Value::Na;
+        assert_eq!(
+            field.abs().to_value_vec(),
+            vec![
+                Value::Exists(1.0),
+                na,
+                Value::Exists(4.0),
+                Value::Exists(2.5)
+            ]
+        );
+        assert_eq!(
+            field.pow(2).to_value_vec(),
+            vec![
+                Value::Exists(1.0),
+                na,
+                Value::Exists(16.0),
+                Value::Exists(6.25)
+            ]
+        );
+        assert!(approx_eq(
+            &field.powf(0.5).to_value_vec(),
+            &field.sqrt().to_value_vec()
+        ));
+        assert!(approx_eq(
+            &field.sqrt().to_value_vec(),
+            &[
+                Value::Exists(1.0),
+                na,
+                Value::Exists(2.0),
+                Value::Exists(::std::f64::NAN)
+            ]
+        ));
+        assert!(approx_eq(
+            &field.ln().to_value_vec(),
+            &[
+                Value::Exists(0.0),
+                na,
+                Value::Exists(4.0f64.ln()),
+                Value::Exists(::std::f64::NAN)
+            ]
+        ));
+        assert!(approx_eq(
+            &field.exp().ln().to_value_vec(),
+            &field.to_value_vec()
+        ));
+        assert_eq!(
+            field.round().to_value_vec(),
+            vec![
+                Value::Exists(1.0),
+                na,
+                Value::Exists(4.0),
+                Value::Exists(-3.0)
+            ]
+        );
+        assert_eq!(
+            field.floor().to_value_vec(),
+            vec![
+                Value::Exists(1.0),
+                na,
+                Value::Exists(4.0),
+                Value::Exists(-3.0)
+            ]
+        );
+        assert_eq!(
+            field.ceil().to_value_vec(),
+            vec![
+                Value::Exists(1.0),
+                na,
+                Value::Exists(4.0),
+                Value::Exists(-2.0)
+            ]
+        );
+        assert_eq!(
+            field.clip(0.0, 2.0).to_value_vec(),
+            vec![
+                Value::Exists(1.0),
+                na,
+                Value::Exists(2.0),
+                Value::Exists(0.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn integer_math() {
+        let field = FieldData::from_field_vec(vec![
+            Value::Exists(-3i64),
+            Value::Exists(2),
+            Value::Na,
+            Value::Exists(10),
+        ]);
+        let na = Value::Na;
+        assert_eq!(
+            field.abs().to_value_vec(),
+            vec![Value::Exists(3), Value::Exists(2), na, Value::Exists(10)]
+        );
+        assert_eq!(
+            field.pow(3).to_value_vec(),
+            vec![
+                Value::Exists(-27),
+                Value::Exists(8),
+                na,
+                Value::Exists(1000)
+            ]
+        );
+        assert_eq!(
+            field.clip(0, 5).to_value_vec(),
+            vec![Value::Exists(0), Value::Exists(2), na, Value::Exists(5)]
+        );
+
+        let field: FieldData<u32> = vec![3u32, 0, 7].into();
+        assert_eq!(field.pow(2).to_vec(), vec![9, 0, 49]);
+        assert_eq!(field.clip(1, 5).to_vec(), vec![3, 1, 5]);
+    }
+}
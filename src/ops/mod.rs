@@ -9,3 +9,58 @@ pub use self::scalar_op::*;
 #[macro_use]
 mod field_op;
 pub use self::field_op::*;
+
+mod view_op;
+pub use self::view_op::*;
+
+mod cmp_op;
+pub use self::cmp_op::*;
+
+mod bool_op;
+pub use self::bool_op::*;
+
+mod math_op;
+pub use self::math_op::*;
+
+mod str_op;
+pub use self::str_op::*;
+
+mod na_op;
+pub use self::na_op::*;
+
+mod window_op;
+pub use self::window_op::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cast::Cast;
+    use field::FieldData;
+    use frame::Framed;
+    use store::DataRef;
+
+    #[test]
+    fn field_types() {
+        // the extension traits are implemented for all field types
+        let field = FieldData::<f64>::from_vec(vec![2.25f64, -9.0]);
+        let framed: Framed<f64> = field.clone().into();
+        let data_ref: DataRef<f64> = field.clone().into();
+
+        assert_eq!(framed.abs(), field.abs());
+        assert_eq!(data_ref.abs(), field.abs());
+        assert_eq!(framed.cast::<i64>(), field.cast::<i64>());
+        assert_eq!(data_ref.cast::<i64>(), field.cast::<i64>());
+        assert_eq!(framed.ffill(), field.ffill());
+        assert_eq!(data_ref.ffill(), field.ffill());
+        let window = Window::trailing(2);
+        assert_eq!(framed.rolling_sum(&window), field.rolling_sum(&window));
+        assert_eq!(data_ref.rolling_sum(&window), field.rolling_sum(&window));
+
+        let field = FieldData::<String>::from_vec(vec![" a ".to_string(), "b".to_string()]);
+        let framed: Framed<String> = field.clone().into();
+        let data_ref: DataRef<String> = field.clone().into();
+
+        assert_eq!(framed.trim(), field.trim());
+        assert_eq!(data_ref.trim(), field.trim());
+    }
+}
@@ -0,0 +1,182 @@
+/*!
+Operations for replacing missing values in fields: filling with a constant value, forward- and
+back-filling, and linear interpolation. Each operation returns a new `FieldData`, and operates in
+the order of the field (for fields selected from a `DataView`, the current filtered and sorted
+order of the `DataView`).
+*/
+use std::fmt::Debug;
+
+use num_traits::AsPrimitive;
+
+use access::DataIndex;
+use field::{FieldData, Value};
+
+/// Extension trait providing methods for replacing the missing values in a field.
+pub trait FillNa: DataIndex + Sized {
+    /// Returns a field with each missing value in this field replaced by `value`.
+    fn fill_na(&self, value: Self::DType) -> FieldData<Self::DType>
+    where
+        Self::DType: Clone + Debug + Default,
+    {
+        self.iter()
+            .map(|datum| match datum {
+                Value::Exists(datum) => datum.clone(),
+                Value::Na => value.clone(),
+            })
+            .collect()
+    }
+
+    /// Returns a field with each missing value in this field replaced by the closest preceding
+    /// existing value. Missing values which are not preceded by any existing values remain missing.
+    fn ffill(&self) -> FieldData<Self::DType>
+    where
+        Self::DType: Clone + Debug + Default,
+    {
+        let mut last = Value::Na;
+        self.iter()
+            .map(|datum| {
+                if datum.exists() {
+                    last = datum;
+                }
+                last.cloned()
+            })
+            .collect()
+    }
+
+    /// Returns a field with each missing value in this field replaced by the closest following
+    /// existing value. Missing values which are not followed by any existing values remain missing.
+    fn bfill(&self) -> FieldData<Self::DType>
+    where
+        Self::DType: Clone + Debug + Default,
+    {
+        let mut next = Value::Na;
+        let mut filled = (0..self.len())
+            .rev()
+            .map(|idx| {
+                // idx is always in range, so unwrap is safe
+                let datum = self.get_datum(idx).unwrap();
+                if datum.exists() {
+                    next = datum;
+                }
+                next
+            })
+            .collect::<Vec<_>>();
+        filled.reverse();
+        filled.into_iter().collect()
+    }
+
+    /// Returns a field with each missing value in this field replaced by linearly interpolating
+    /// (by position) between the closest preceding and following existing values. Missing values
+    /// which are not both preceded and followed by existing values remain missing.
+    fn interpolate(&self) -> FieldData<f64>
+    where
+        Self::DType: AsPrimitive<f64>,
+    {
+        let mut interpolated = self
+            .iter()
+            .map(|datum| datum.map(|&datum| datum.as_()))
+            .collect::<Vec<Value<f64>>>();
+        let mut prev: Option<(usize, f64)> = None;
+        for idx in 0..interpolated.len() {
+            if let Value::Exists(value) = interpolated[idx] {
+                if let Some((prev_idx, prev_value)) = prev {
+                    let span = (idx - prev_idx) as f64;
+                    for (offset, fill_idx) in (prev_idx + 1..idx).enumerate() {
+                        let weight = (offset + 1) as f64 / span;
+                        interpolated[fill_idx] =
+                            Value::Exists(prev_value + (value - prev_value) * weight);
+                    }
+                }
+                prev = Some((idx, value));
+            }
+        }
+        interpolated.into_iter().collect()
+    }
+}
+
+impl<DI> FillNa for DI where DI: DataIndex {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fill() {
+        let field = FieldData::from_field_vec(vec![
+            Value::Na,
+            Value::Exists(2i64),
+            Value::Na,
+            Value::Na,
+            Value::Exists(8),
+            Value::Na,
+        ]);
+        let na = Value::Na;
+        assert_eq!(field.fill_na(0).to_vec(), vec![0, 2, 0, 0, 8, 0]);
+        assert_eq!(
+            field.ffill().to_value_vec(),
+            vec![
+                na,
+                Value::Exists(2),
+                Value::Exists(2),
+                Value::Exists(2),
+                Value::Exists(8),
+                Value::Exists(8)
+            ]
+        );
+        assert_eq!(
+            field.bfill().to_value_vec(),
+            vec![
+                Value::Exists(2),
+                Value::Exists(2),
+                Value::Exists(8),
+                Value::Exists(8),
+                Value::Exists(8),
+                na
+            ]
+        );
+        assert_eq!(field.ffill().bfill().to_vec(), vec![2, 2, 2, 2, 8, 8]);
+
+        let names = FieldData::from_field_vec(vec![
+            Value::Exists("a".to_string()),
+            Value::Na,
+            Value::Exists("b".to_string()),
+        ]);
+        assert_eq!(names.ffill().to_vec(), vec!["a", "a", "b"]);
+        assert_eq!(names.bfill().to_vec(), vec!["a", "b", "b"]);
+        assert_eq!(names.fill_na("?".to_string()).to_vec(), vec!["a", "?", "b"]);
+    }
+
+    #[test]
+    fn interpolate() {
+        let field = FieldData::from_field_vec(vec![
+            Value::Na,
+            Value::Exists(2i64),
+            Value::Na,
+            Value::Na,
+            Value::Exists(8),
+            Value::Na,
+        ]);
+        let na = Value::Na;
+        assert_eq!(
+            field.interpolate().to_value_vec(),
+            vec![
+                na,
+                Value::Exists(2.0),
+                Value::Exists(4.0),
+                Value::Exists(6.0),
+                Value::Exists(8.0),
+                na
+            ]
+        );
+
+        let field = FieldData::from_field_vec(vec![
+            Value::Exists(1.0f32),
+            Value::Na,
+            Value::Exists(0.0),
+            Value::Exists(0.5),
+        ]);
+        assert_eq!(field.interpolate().to_vec(), vec![1.0, 0.5, 0.0, 0.5]);
+        let field = FieldData::<f64>::from_field_vec(vec![Value::Na, Value::Na]);
+        assert_eq!(field.interpolate().to_value_vec(), vec![na, na]);
+    }
+}
@@ -1,5 +1,5 @@
 use std::fmt::Debug;
-use std::ops::{Add, Div, Mul, Sub};
+use std::ops::{Add, Div, Mul, Neg, Sub};
 
 use access::{DataIndex, DataIndexMut};
 use field::FieldData;
@@ -79,6 +79,42 @@ impl_op![Sub sub; [FieldData<T>] [Framed<T>] [DataRef<T>]];
 impl_op![Mul mul; [FieldData<T>] [Framed<T>] [DataRef<T>]];
 impl_op![Div div; [FieldData<T>] [Framed<T>] [DataRef<T>]];
 
+macro_rules! impl_unary_op {
+    ($trait_name:tt $trait_fn:tt; $([$($ty_tt:tt)*])*) => {$(
+
+        // <op> &data
+        impl<'a, T> $trait_name for &'a $($ty_tt)*
+        where
+            $($ty_tt)*: DataIndex<DType=T>,
+            &'a T: $trait_name,
+            <&'a T as $trait_name>::Output: Debug + Default,
+        {
+            type Output = FieldData<<&'a T as $trait_name>::Output>;
+
+            fn $trait_fn(self) -> Self::Output {
+                self.iter().map(|val| val.map(|val| val.$trait_fn())).collect()
+            }
+        }
+
+        // <op> data
+        impl<T> $trait_name for $($ty_tt)*
+        where
+            $($ty_tt)*: DataIndexMut<DType=T>,
+            T: $trait_name + Default,
+            <T as $trait_name>::Output: Debug + Default,
+        {
+            type Output = FieldData<<T as $trait_name>::Output>;
+
+            fn $trait_fn(mut self) -> Self::Output {
+                self.drain().map(|val| val.map(|val| val.$trait_fn())).collect()
+            }
+        }
+
+    )*}
+}
+
+impl_unary_op![Neg neg; [FieldData<T>] [Framed<T>] [DataRef<T>]];
+
 macro_rules! impl_op_nongeneric {
     ($dtype:ty; $trait_name:tt $trait_fn:tt; $([$($ty_tt:tt)*])*) => {$(
 
@@ -297,6 +333,35 @@ mod tests {
         ];
     }
 
+    #[test]
+    fn neg() {
+        use field::Value;
+        use frame::Framed;
+        use store::DataRef;
+
+        let data = FieldData::from_field_vec(vec![
+            Value::Exists(2i64),
+            Value::Exists(-3),
+            Value::Na,
+            Value::Exists(0),
+        ]);
+        let expected = vec![
+            Value::Exists(-2i64),
+            Value::Exists(3),
+            Value::Na,
+            Value::Exists(0),
+        ];
+        assert_eq![(-&data).to_value_vec(), expected];
+        assert_eq![(-data.clone()).to_value_vec(), expected];
+        let framed: Framed<i64> = data.clone().into();
+        assert_eq![(-&framed).to_value_vec(), expected];
+        let data_ref: DataRef<i64> = data.clone().into();
+        assert_eq![(-&data_ref).to_value_vec(), expected];
+
+        let data: FieldData<f64> = vec![2.5f64, -3.0, 0.5].into();
+        assert_eq![(-&data).to_vec(), vec![-2.5, 3.0, -0.5]];
+    }
+
     #[test]
     #[should_panic]
     fn div_zero_scalar() {
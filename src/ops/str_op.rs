@@ -0,0 +1,234 @@
+/*!
+Element-wise string operations (trimming, case conversion, searching, replacing, regular expression
+matching and extraction, and splitting) for `String` fields. Each operation returns a new
+`FieldData` containing the result for each value of the original field; missing values remain
+missing.
+
+Operations producing boolean fields (such as `contains` or `is_match`) can be used with
+[filter_mask](../view/struct.DataView.html#method.filter_mask), and any resulting field can be
+added to a `DataView` using [with_field](../view/struct.DataView.html#method.with_field).
+*/
+use std::fmt::Debug;
+
+pub use regex::Regex;
+
+use access::DataIndex;
+use field::{FieldData, Value};
+
+fn map_str<DI, T, F>(data: &DI, f: F) -> FieldData<T>
+where
+    DI: DataIndex<DType = String>,
+    T: Debug + Default,
+    F: Fn(&str) -> T,
+{
+    data.iter()
+        .map(|value| value.map(|value| f(value)))
+        .collect()
+}
+
+fn filter_map_str<DI, T, F>(data: &DI, f: F) -> FieldData<T>
+where
+    DI: DataIndex<DType = String>,
+    T: Debug + Default,
+    F: Fn(&str) -> Option<T>,
+{
+    data.iter()
+        .map(|value| match value {
+            Value::Exists(value) => f(value).into(),
+            Value::Na => Value::Na,
+        })
+        .collect()
+}
+
+/// Extension trait providing element-wise string operations for `String` fields.
+pub trait FieldStr: DataIndex<DType = String> + Sized {
+    /// Returns a field containing each value in this field with leading and trailing whitespace
+    /// removed.
+    fn trim(&self) -> FieldData<String> {
+        map_str(self, |value| value.trim().to_string())
+    }
+    /// Returns a field containing each value in this field converted to lowercase.
+    fn to_lowercase(&self) -> FieldData<String> {
+        map_str(self, str::to_lowercase)
+    }
+    /// Returns a field containing each value in this field converted to uppercase.
+    fn to_uppercase(&self) -> FieldData<String> {
+        map_str(self, str::to_uppercase)
+    }
+    /// Returns a field containing the length (in characters) of each value in this field.
+    fn str_len(&self) -> FieldData<usize> {
+        map_str(self, |value| value.chars().count())
+    }
+    /// Returns a boolean field which is `true` where this field's values contain `pattern`.
+    fn contains(&self, pattern: &str) -> FieldData<bool> {
+        map_str(self, |value| value.contains(pattern))
+    }
+    /// Returns a boolean field which is `true` where this field's values start with `pattern`.
+    fn starts_with(&self, pattern: &str) -> FieldData<bool> {
+        map_str(self, |value| value.starts_with(pattern))
+    }
+    /// Returns a boolean field which is `true` where this field's values end with `pattern`.
+    fn ends_with(&self, pattern: &str) -> FieldData<bool> {
+        map_str(self, |value| value.ends_with(pattern))
+    }
+    /// Returns a field containing each value in this field with all occurrences of `from` replaced
+    /// with `to`.
+    fn replace(&self, from: &str, to: &str) -> FieldData<String> {
+        map_str(self, |value| value.replace(from, to))
+    }
+    /// Returns a boolean field which is `true` where this field's values match the regular
+    /// expression `regex`.
+    fn is_match(&self, regex: &Regex) -> FieldData<bool> {
+        map_str(self, |value| regex.is_match(value))
+    }
+    /// Returns a field containing the text matched by capture group `group` of the regular
+    /// expression `regex` (where group `0` is the entire match) in each value of this field. The
+    /// result is missing for values which do not match `regex`, or where the group did not
+    /// participate in the match.
+    fn extract(&self, regex: &Regex, group: usize) -> FieldData<String> {
+        filter_map_str(self, |value| {
+            regex
+                .captures(value)
+                .and_then(|captures| captures.get(group))
+                .map(|matched| matched.as_str().to_string())
+        })
+    }
+    /// Returns a field containing the `n`th (starting at `0`) substring of each value in this
+    /// field, when split by `separator`. The result is missing for values which have fewer than
+    /// `n + 1` substrings.
+    fn split_nth(&self, separator: &str, n: usize) -> FieldData<String> {
+        filter_map_str(self, |value| {
+            value.split(separator).nth(n).map(str::to_string)
+        })
+    }
+}
+
+impl<DI> FieldStr for DI where DI: DataIndex<DType = String> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[Option<&str>]) -> Vec<Value<String>> {
+        values
+            .iter()
+            .map(|value| Value::from(value.map(str::to_string)))
+            .collect()
+    }
+
+    #[test]
+    fn transform() {
+        let field = FieldData::from_field_vec(vec![
+            Value::Exists("  United States ".to_string()),
+            Value::Na,
+            Value::Exists("Korea, Rep.".to_string()),
+            Value::Exists("Europe & Central Asia (IDA)".to_string()),
+        ]);
+        assert_eq!(
+            field.trim().to_value_vec(),
+            strings(&[
+                Some("United States"),
+                None,
+                Some("Korea, Rep."),
+                Some("Europe & Central Asia (IDA)")
+            ])
+        );
+        assert_eq!(
+            field.trim().to_lowercase().to_value_vec(),
+            strings(&[
+                Some("united states"),
+                None,
+                Some("korea, rep."),
+                Some("europe & central asia (ida)")
+            ])
+        );
+        assert_eq!(
+            field.to_uppercase().to_value_vec()[2],
+            Value::Exists("KOREA, REP.".to_string())
+        );
+        assert_eq!(
+            field.str_len().to_value_vec(),
+            vec![
+                Value::Exists(16),
+                Value::Na,
+                Value::Exists(11),
+                Value::Exists(27)
+            ]
+        );
+        assert_eq!(
+            field.replace(" & ", " and ").to_value_vec()[3],
+            Value::Exists("Europe and Central Asia (IDA)".to_string())
+        );
+        assert_eq!(
+            field.split_nth(",", 1).to_value_vec(),
+            strings(&[None, None, Some(" Rep."), None])
+        );
+        assert_eq!(
+            field.split_nth(" ", 0).to_value_vec(),
+            strings(&[Some(""), None, Some("Korea,"), Some("Europe")])
+        );
+    }
+
+    #[test]
+    fn search() {
+        let field = FieldData::from_field_vec(vec![
+            Value::Exists("United States".to_string()),
+            Value::Na,
+            Value::Exists("Korea, Rep.".to_string()),
+            Value::Exists("Europe & Central Asia (IDA)".to_string()),
+        ]);
+        let na = Value::Na;
+        assert_eq!(
+            field.contains("Rep").to_value_vec(),
+            vec![
+                Value::Exists(false),
+                na,
+                Value::Exists(true),
+                Value::Exists(false)
+            ]
+        );
+        assert_eq!(
+            field.starts_with("United").to_value_vec(),
+            vec![
+                Value::Exists(true),
+                na,
+                Value::Exists(false),
+                Value::Exists(false)
+            ]
+        );
+        assert_eq!(
+            field.ends_with(")").to_value_vec(),
+            vec![
+                Value::Exists(false),
+                na,
+                Value::Exists(false),
+                Value::Exists(true)
+            ]
+        );
+
+        let regex = Regex::new(r"\((\w+)\)$").unwrap();
+        assert_eq!(
+            field.is_match(&regex).to_value_vec(),
+            vec![
+                Value::Exists(false),
+                na,
+                Value::Exists(false),
+                Value::Exists(true)
+            ]
+        );
+        assert_eq!(
+            field.extract(&regex, 1).to_value_vec(),
+            strings(&[None, None, None, Some("IDA")])
+        );
+        assert_eq!(
+            field.extract(&regex, 0).to_value_vec(),
+            strings(&[None, None, None, Some("(IDA)")])
+        );
+        // optional group which does not participate in the match
+        let regex = Regex::new(r"^(\w+)(, (\w+))?").unwrap();
+        assert_eq!(
+            field.extract(&regex, 3).to_value_vec(),
+            strings(&[None, None, Some("Rep"), None])
+        );
+    }
+}
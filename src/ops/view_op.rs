@@ -0,0 +1,209 @@
+/*!
+Implementation of arithmetic operations (`Add`, `Sub`, `Mul`, `Div`) between two labeled fields of a
+`DataView`, where the result is added to the `DataView` as a new labeled field.
+*/
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Sub};
+
+use access::DataIndex;
+use error;
+use field::{FieldData, Value};
+use select::SelectFieldByLabel;
+use view::{DataView, IntoFieldView, SingleFieldView, VFieldTypeOf, ViewMerge, WithFieldView};
+
+/// A trait for an arithmetic operation between a value of type `L` and a value of type `R`.
+pub trait FieldArith<L, R> {
+    /// Output of the arithmetic operation.
+    type Output;
+
+    /// Apply this operation to `left` and `right`.
+    fn apply(left: &L, right: &R) -> Self::Output;
+}
+
+macro_rules! impl_field_arith {
+    ($($(#[$doc:meta])* $name:ident $trait_name:ident $trait_fn:ident;)*) => {$(
+        $(#[$doc])*
+        #[derive(Debug, Clone)]
+        pub struct $name;
+
+        impl<L, R> FieldArith<L, R> for $name
+        where
+            L: $trait_name<R> + Clone,
+            R: Clone,
+        {
+            type Output = <L as $trait_name<R>>::Output;
+
+            fn apply(left: &L, right: &R) -> Self::Output {
+                left.clone().$trait_fn(right.clone())
+            }
+        }
+    )*}
+}
+
+impl_field_arith![
+    /// Addition operation for use with [arith_fields](../view/struct.DataView.html#method.arith_fields).
+    Plus Add add;
+    /// Subtraction operation for use with
+    /// [arith_fields](../view/struct.DataView.html#method.arith_fields).
+    Minus Sub sub;
+    /// Multiplication operation for use with
+    /// [arith_fields](../view/struct.DataView.html#method.arith_fields).
+    Times Mul mul;
+    /// Division operation for use with [arith_fields](../view/struct.DataView.html#method.arith_fields).
+    Divide Div div;
+];
+
+/// Type alias for the data type of the result of the arithmetic operation `Op` between the fields
+/// labeled `Left` and `Right` of the `DataView` `View`.
+pub type ArithTypeOf<View, Op, Left, Right> =
+    <Op as FieldArith<VFieldTypeOf<View, Left>, VFieldTypeOf<View, Right>>>::Output;
+
+/// Type alias for the `DataView` resulting from adding the result of the arithmetic operation `Op`
+/// between the fields labeled `Left` and `Right` of the `DataView` `View` as the field `Out`.
+pub type ArithView<View, Op, Left, Right, Out> =
+    WithFieldView<View, Out, ArithTypeOf<View, Op, Left, Right>>;
+
+macro_rules! impl_arith_fields_fn {
+    ($($(#[$doc:meta])* $fn_name:ident $op:ident;)*) => {$(
+        $(#[$doc])*
+        pub fn $fn_name<Left, Right, Out>(
+            &self,
+        ) -> error::Result<ArithView<Self, $op, Left, Right, Out>>
+        where
+            Self: SelectFieldByLabel<Left> + SelectFieldByLabel<Right>,
+            $op: FieldArith<VFieldTypeOf<Self, Left>, VFieldTypeOf<Self, Right>>,
+            ArithTypeOf<Self, $op, Left, Right>: Debug + Default,
+            FieldData<ArithTypeOf<Self, $op, Left, Right>>: IntoFieldView<Out>,
+            Self: ViewMerge<SingleFieldView<Out, ArithTypeOf<Self, $op, Left, Right>>>,
+        {
+            self.arith_fields::<$op, Left, Right, Out>()
+        }
+    )*}
+}
+
+impl<Labels, Frames> DataView<Labels, Frames> {
+    /// Construct a new `DataView` with all the fields of this `DataView` along with a new field
+    /// labeled `Out`, the result of applying the arithmetic operation `Op` (one of
+    /// [Plus](struct.Plus.html), [Minus](struct.Minus.html), [Times](struct.Times.html), or
+    /// [Divide](struct.Divide.html)) between the fields labeled `Left` and `Right` of each
+    /// record. The fields can be in different frames of this `DataView`; each is accessed in the
+    /// current (filtered and sorted) record order. If either value in a record is missing, the
+    /// result is missing.
+    pub fn arith_fields<Op, Left, Right, Out>(
+        &self,
+    ) -> error::Result<ArithView<Self, Op, Left, Right, Out>>
+    where
+        Self: SelectFieldByLabel<Left> + SelectFieldByLabel<Right>,
+        Op: FieldArith<VFieldTypeOf<Self, Left>, VFieldTypeOf<Self, Right>>,
+        ArithTypeOf<Self, Op, Left, Right>: Debug + Default,
+        FieldData<ArithTypeOf<Self, Op, Left, Right>>: IntoFieldView<Out>,
+        Self: ViewMerge<SingleFieldView<Out, ArithTypeOf<Self, Op, Left, Right>>>,
+    {
+        let left = SelectFieldByLabel::<Left>::select_field(self);
+        let right = SelectFieldByLabel::<Right>::select_field(self);
+        let data = left
+            .iter()
+            .zip(right.iter())
+            .map(|values| match values {
+                (Value::Exists(left), Value::Exists(right)) => {
+                    Value::Exists(Op::apply(left, right))
+                }
+                _ => Value::Na,
+            })
+            .collect::<FieldData<_>>();
+        self.with_field::<Out, _>(data)
+    }
+
+    impl_arith_fields_fn![
+        /// Construct a new `DataView` with a new field labeled `Out` containing the sum of the
+        /// fields labeled `Left` and `Right`. See
+        /// [arith_fields](struct.DataView.html#method.arith_fields) for details.
+        add_fields Plus;
+        /// Construct a new `DataView` with a new field labeled `Out` containing the difference of
+        /// the fields labeled `Left` and `Right`. See
+        /// [arith_fields](struct.DataView.html#method.arith_fields) for details.
+        sub_fields Minus;
+        /// Construct a new `DataView` with a new field labeled `Out` containing the product of the
+        /// fields labeled `Left` and `Right`. See
+        /// [arith_fields](struct.DataView.html#method.arith_fields) for details.
+        mul_fields Times;
+        /// Construct a new `DataView` with a new field labeled `Out` containing the quotient of the
+        /// fields labeled `Left` and `Right`. See
+        /// [arith_fields](struct.DataView.html#method.arith_fields) for details.
+        div_fields Divide;
+    ];
+}
+
+#[cfg(all(test, feature = "test-utils"))]
+mod tests {
+    use access::DataIndex;
+    use field::{FieldData, Value};
+    use select::FieldSelect;
+    use test_utils::*;
+
+    namespace![
+        @continue(typenum::Add1<::test_utils::dept_table::Table>)
+        table arith_tbl {
+            Total: i64,
+            Diff: i64,
+            Prod: u64,
+            Ratio: f32,
+        }
+    ];
+
+    #[test]
+    fn arith_fields() {
+        use test_utils::emp_table::*;
+        use test_utils::extra_emp::*;
+
+        let mut dv = sample_merged_emp_table();
+        dv.filter::<DidTraining, _>(|val: Value<&bool>| val == valref![true]);
+        dv.sort_by_label::<EmpName>();
+        assert_eq!(
+            dv.field::<EmpName>().to_vec(),
+            vec!["Ann", "Bob", "Cara", "Louis"]
+        );
+
+        let dv = dv
+            .mul_fields::<EmpId, DeptId, arith_tbl::Prod>()
+            .unwrap()
+            .div_fields::<VacationHrs, VacationHrs, arith_tbl::Ratio>()
+            .unwrap();
+        assert_eq!(
+            dv.field::<arith_tbl::Prod>().to_vec(),
+            vec![40u64, 5, 6, 24]
+        );
+        assert_eq!(
+            dv.field::<arith_tbl::Ratio>().to_vec(),
+            vec![1.0f32, 1.0, 1.0, 1.0]
+        );
+
+        let dv = dv
+            .relabel::<SalaryOffset, arith_tbl::Total>()
+            .add_fields::<arith_tbl::Total, arith_tbl::Total, arith_tbl::Diff>()
+            .unwrap();
+        assert_eq!(
+            dv.field::<arith_tbl::Diff>().to_vec(),
+            vec![-2i64, 24, -66, 20]
+        );
+    }
+
+    #[test]
+    fn arith_fields_na() {
+        use test_utils::emp_table::*;
+
+        let ds: emp_table::Store = emp_table_from_field![
+            FieldData::from_field_vec(vec![Value::Exists(5u64), Value::Na, Value::Exists(3),]),
+            FieldData::from_field_vec(vec![Value::Exists(2u64), Value::Exists(4), Value::Na,]),
+            FieldData::from(vec!["a".to_string(), "b".to_string(), "c".to_string()])
+        ];
+        let dv = ds
+            .into_view()
+            .sub_fields::<EmpId, DeptId, arith_tbl::Prod>()
+            .unwrap();
+        assert_eq!(
+            dv.field::<arith_tbl::Prod>().iter().collect::<Vec<_>>(),
+            vec![valref![3u64], Value::Na, Value::Na]
+        );
+    }
+}
@@ -0,0 +1,257 @@
+/*!
+Rolling-window aggregates (sum, mean, minimum and maximum) for numeric fields. Each aggregate
+returns a new `FieldData<f64>` with one value for each value of the original field, computed over
+the window of neighbouring values as described by a [Window](struct.Window.html). Windows are
+formed in the order of the field (for fields selected from a `DataView`, the current filtered and
+sorted order of the `DataView`), so a time series view should be sorted by date before computing
+rolling aggregates.
+
+Missing values within a window are ignored; the result for a window is missing if the window
+contains fewer than the window's minimum number of existing values. The resulting field can be
+added to a `DataView` using [with_field](../view/struct.DataView.html#method.with_field).
+*/
+use num_traits::AsPrimitive;
+
+use access::DataIndex;
+use field::{FieldData, Value};
+
+/// Alignment of a rolling window relative to the value it produces a result for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowAlign {
+    /// The window ends at (and includes) the current value, and extends over the preceding
+    /// values.
+    Trailing,
+    /// The window is centered on the current value. For windows of even size, the window extends
+    /// one more value before the current value than after it.
+    Centered,
+}
+
+/// Description of a rolling window: its size, alignment, and the minimum number of existing
+/// (non-missing) values required within a window to produce a result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Window {
+    size: usize,
+    min_periods: usize,
+    align: WindowAlign,
+}
+
+impl Window {
+    /// Creates a trailing window of `size` values. By default, a result is only produced for
+    /// windows where all `size` values exist.
+    pub fn trailing(size: usize) -> Window {
+        Window {
+            size,
+            min_periods: size,
+            align: WindowAlign::Trailing,
+        }
+    }
+    /// Creates a centered window of `size` values. By default, a result is only produced for
+    /// windows where all `size` values exist.
+    pub fn centered(size: usize) -> Window {
+        Window {
+            size,
+            min_periods: size,
+            align: WindowAlign::Centered,
+        }
+    }
+    /// Sets the minimum number of existing values required within a window to produce a result
+    /// (a minimum of `0` is treated as `1`). Windows which extend past the start or end of the
+    /// field contain fewer values, and also produce results if they meet this minimum.
+    pub fn min_periods(mut self, min_periods: usize) -> Window {
+        self.min_periods = min_periods;
+        self
+    }
+    /// Returns the number of values in this window.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+    /// Returns the alignment of this window.
+    pub fn align(&self) -> WindowAlign {
+        self.align
+    }
+
+    // index range (start inclusive, end exclusive) of the window for value `idx` in a field of
+    // length `len`
+    fn bounds(&self, idx: usize, len: usize) -> (usize, usize) {
+        if self.size == 0 {
+            return (idx, idx);
+        }
+        let (before, after) = match self.align {
+            WindowAlign::Trailing => (self.size - 1, 0),
+            WindowAlign::Centered => (self.size / 2, (self.size - 1) / 2),
+        };
+        (idx.saturating_sub(before), (idx + after + 1).min(len))
+    }
+}
+
+fn rolling_apply<DI, F>(data: &DI, window: &Window, f: F) -> FieldData<f64>
+where
+    DI: DataIndex,
+    DI::DType: AsPrimitive<f64>,
+    // `f` is only called with non-empty windows
+    F: Fn(&[f64]) -> f64,
+{
+    let values = data
+        .iter()
+        .map(|datum| datum.map(|&datum| datum.as_()))
+        .collect::<Vec<Value<f64>>>();
+    let min_periods = window.min_periods.max(1);
+    let mut existing = Vec::with_capacity(window.size);
+    (0..values.len())
+        .map(|idx| {
+            let (start, end) = window.bounds(idx, values.len());
+            existing.clear();
+            existing.extend(values[start..end].iter().filter_map(|value| match *value {
+                Value::Exists(value) => Some(value),
+                Value::Na => None,
+            }));
+            if existing.len() >= min_periods {
+                Value::Exists(f(&existing))
+            } else {
+                Value::Na
+            }
+        })
+        .collect()
+}
+
+/// Extension trait providing rolling-window aggregates for numeric fields.
+pub trait Rolling: DataIndex + Sized {
+    /// Returns a field containing the sum of the existing values within each `window` of this
+    /// field.
+    fn rolling_sum(&self, window: &Window) -> FieldData<f64>
+    where
+        Self::DType: AsPrimitive<f64>,
+    {
+        rolling_apply(self, window, |values| values.iter().sum())
+    }
+    /// Returns a field containing the mean of the existing values within each `window` of this
+    /// field.
+    fn rolling_mean(&self, window: &Window) -> FieldData<f64>
+    where
+        Self::DType: AsPrimitive<f64>,
+    {
+        rolling_apply(self, window, |values| {
+            values.iter().sum::<f64>() / values.len() as f64
+        })
+    }
+    /// Returns a field containing the minimum of the existing values within each `window` of
+    /// this field.
+    fn rolling_min(&self, window: &Window) -> FieldData<f64>
+    where
+        Self::DType: AsPrimitive<f64>,
+    {
+        rolling_apply(self, window, |values| {
+            values[1..].iter().cloned().fold(values[0], f64::min)
+        })
+    }
+    /// Returns a field containing the maximum of the existing values within each `window` of
+    /// this field.
+    fn rolling_max(&self, window: &Window) -> FieldData<f64>
+    where
+        Self::DType: AsPrimitive<f64>,
+    {
+        rolling_apply(self, window, |values| {
+            values[1..].iter().cloned().fold(values[0], f64::max)
+        })
+    }
+}
+
+impl<DI> Rolling for DI where DI: DataIndex {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trailing() {
+        let field = FieldData::from_field_vec(vec![
+            Value::Exists(1i64),
+            Value::Exists(4),
+            Value::Na,
+            Value::Exists(2),
+            Value::Exists(8),
+            Value::Exists(3),
+        ]);
+        let na = Value::Na;
+        let window = Window::trailing(2);
+        assert_eq!(
+            field.rolling_sum(&window).to_value_vec(),
+            vec![
+                na,
+                Value::Exists(5.0),
+                na,
+                na,
+                Value::Exists(10.0),
+                Value::Exists(11.0)
+            ]
+        );
+        let window = Window::trailing(3).min_periods(1);
+        assert_eq!(
+            field.rolling_mean(&window).to_vec(),
+            vec![1.0, 2.5, 2.5, 3.0, 5.0, 13.0 / 3.0]
+        );
+        assert_eq!(
+            field.rolling_min(&window).to_vec(),
+            vec![1.0, 1.0, 1.0, 2.0, 2.0, 2.0]
+        );
+        assert_eq!(
+            field.rolling_max(&window).to_vec(),
+            vec![1.0, 4.0, 4.0, 4.0, 8.0, 8.0]
+        );
+        let window = Window::trailing(3).min_periods(2);
+        assert_eq!(
+            field.rolling_max(&window).to_value_vec(),
+            vec![
+                na,
+                Value::Exists(4.0),
+                Value::Exists(4.0),
+                Value::Exists(4.0),
+                Value::Exists(8.0),
+                Value::Exists(8.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn centered() {
+        let field = FieldData::from_field_vec(vec![
+            Value::Exists(1i64),
+            Value::Exists(4),
+            Value::Na,
+            Value::Exists(2),
+            Value::Exists(8),
+            Value::Exists(3),
+        ]);
+        let na = Value::Na;
+        let window = Window::centered(3);
+        assert_eq!(window.align(), WindowAlign::Centered);
+        assert_eq!(
+            field.rolling_sum(&window).to_value_vec(),
+            vec![na, na, na, na, Value::Exists(13.0), na]
+        );
+        let window = window.min_periods(2);
+        assert_eq!(
+            field.rolling_max(&window).to_vec(),
+            vec![4.0, 4.0, 4.0, 8.0, 8.0, 8.0]
+        );
+        // even-sized windows extend further before the current value than after it
+        let window = Window::centered(4).min_periods(1);
+        assert_eq!(
+            field.rolling_min(&window).to_vec(),
+            vec![1.0, 1.0, 1.0, 2.0, 2.0, 2.0]
+        );
+
+        let field = FieldData::<f32>::from_vec(vec![0.5f32, 1.5, 2.5]);
+        assert_eq!(
+            field
+                .rolling_mean(&Window::centered(3).min_periods(1))
+                .to_vec(),
+            vec![1.0, 1.5, 2.0]
+        );
+        // empty windows never produce results
+        assert_eq!(
+            field.rolling_sum(&Window::trailing(0)).to_value_vec(),
+            vec![Value::Na, Value::Na, Value::Na]
+        );
+    }
+}
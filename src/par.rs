@@ -0,0 +1,274 @@
+/*!
+Support for splitting per-field work across threads. Work is only split across threads when the
+`parallel` feature is enabled; otherwise, everything runs on the calling thread.
+
+With the `parallel` feature, work is scheduled on the global [rayon](https://docs.rs/rayon) thread
+pool, so the number of threads is bounded by the size of that pool, even when parallel work (such
+as per-field statistics) itself calls other parallel operations (such as chunked reductions).
+
+With the `parallel` feature, exact reductions (counts of missing and existing values, and boolean
+`any` / `all`) are computed over fixed-size chunks of a field, with the chunk results combined in
+order. Sums and sums of squares are always folded over the values of a field in order, since
+floating-point addition is not associative; results are therefore identical with or without the
+`parallel` feature.
+
+Parallel entry points require the data they share between threads to be `Sync` (see
+[MaybeSync](trait.MaybeSync.html)) only when the `parallel` feature is enabled.
+*/
+use std::cmp::Ordering;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use access::DataIndex;
+use field::Value;
+
+/// Number of values in each chunk of a chunked (exact) field reduction when the `parallel` feature
+/// is enabled. Fields shorter than this are reduced in a single chunk.
+pub const CHUNK_SIZE: usize = 1 << 16;
+
+/// Marker trait for types which need to be shared between threads. When the `parallel` feature is
+/// enabled, this is implemented for all `Sync` types; otherwise, it is implemented for all types.
+#[cfg(feature = "parallel")]
+pub trait MaybeSync: Sync {}
+#[cfg(feature = "parallel")]
+impl<T> MaybeSync for T where T: Sync + ?Sized {}
+/// Marker trait for types which need to be shared between threads. When the `parallel` feature is
+/// enabled, this is implemented for all `Sync` types; otherwise, it is implemented for all types.
+#[cfg(not(feature = "parallel"))]
+pub trait MaybeSync {}
+#[cfg(not(feature = "parallel"))]
+impl<T> MaybeSync for T where T: ?Sized {}
+
+/// Marker trait for types which need to be sent between threads. When the `parallel` feature is
+/// enabled, this is implemented for all `Send` types; otherwise, it is implemented for all types.
+#[cfg(feature = "parallel")]
+pub trait MaybeSend: Send {}
+#[cfg(feature = "parallel")]
+impl<T> MaybeSend for T where T: Send + ?Sized {}
+/// Marker trait for types which need to be sent between threads. When the `parallel` feature is
+/// enabled, this is implemented for all `Send` types; otherwise, it is implemented for all types.
+#[cfg(not(feature = "parallel"))]
+pub trait MaybeSend {}
+#[cfg(not(feature = "parallel"))]
+impl<T> MaybeSend for T where T: ?Sized {}
+
+/// Reduces the values in `data` by folding them in order (starting with `init()`) using `fold`.
+#[cfg(not(feature = "parallel"))]
+pub(crate) fn fold_chunks<DI, T, Init, Fold, Combine>(
+    data: &DI,
+    init: Init,
+    fold: Fold,
+    _combine: Combine,
+) -> T
+where
+    DI: DataIndex + MaybeSync,
+    T: MaybeSend,
+    Init: Fn() -> T + MaybeSync,
+    Fold: Fn(T, Value<&DI::DType>) -> T + MaybeSync,
+    Combine: Fn(T, T) -> T,
+{
+    data.iter().fold(init(), fold)
+}
+/// Reduces the values in `data` by folding each chunk of [CHUNK_SIZE](constant.CHUNK_SIZE.html)
+/// values (starting with `init()`) using `fold`, then folding the chunk results (in order) using
+/// `combine`. Chunks are folded in parallel, so this should only be used for reductions where the
+/// result doesn't depend on how the values are grouped (e.g. counts).
+#[cfg(feature = "parallel")]
+pub(crate) fn fold_chunks<DI, T, Init, Fold, Combine>(
+    data: &DI,
+    init: Init,
+    fold: Fold,
+    combine: Combine,
+) -> T
+where
+    DI: DataIndex + MaybeSync,
+    T: MaybeSend,
+    Init: Fn() -> T + MaybeSync,
+    Fold: Fn(T, Value<&DI::DType>) -> T + MaybeSync,
+    Combine: Fn(T, T) -> T,
+{
+    let len = data.len();
+    let chunk_results = (0..len.div_ceil(CHUNK_SIZE))
+        .into_par_iter()
+        .map(|chunk| {
+            let start = chunk * CHUNK_SIZE;
+            let end = (start + CHUNK_SIZE).min(len);
+            // indices are always in range, so unwrap is safe
+            (start..end).fold(init(), |acc, idx| fold(acc, data.get_datum(idx).unwrap()))
+        })
+        .collect::<Vec<_>>();
+    chunk_results.into_iter().fold(init(), combine)
+}
+
+/// Computes the stable sorted order of the indices `0..len`, as compared with `compare`. With the
+/// `parallel` feature, the indices are sorted in parallel.
+pub(crate) fn stable_sort_order<F>(len: usize, compare: F) -> Vec<usize>
+where
+    F: Fn(usize, usize) -> Ordering + MaybeSync,
+{
+    let mut order = (0..len).collect::<Vec<_>>();
+    #[cfg(not(feature = "parallel"))]
+    order.sort_by(|&left, &right| compare(left, right));
+    #[cfg(feature = "parallel")]
+    order.par_sort_by(|&left, &right| compare(left, right));
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use access::SortOrder;
+    use field::FieldData;
+    use stats::{AnyAll, NaCount, Sum, SumSq};
+
+    fn sample_field(len: usize) -> FieldData<f64> {
+        // deterministic pseudo-random values with repeats and missing values
+        let mut state = 12345u64;
+        (0..len)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                match state >> 60 {
+                    0 => Value::Na,
+                    _ => Value::Exists(((state >> 40) % 1000) as f64 / 7.0),
+                }
+            })
+            .collect()
+    }
+
+    // sums and sums of squares folded in order, as computed without the `parallel` feature
+    fn sequential_sums(values: &[Value<f64>]) -> (f64, f64) {
+        values
+            .iter()
+            .fold((0.0, 0.0), |(sum, sum_sq), value| match *value {
+                Value::Exists(value) => (sum + value, sum_sq + value * value),
+                Value::Na => (sum, sum_sq),
+            })
+    }
+
+    fn expected_sort_order(field: &FieldData<u64>) -> Vec<usize> {
+        let mut expected = (0..field.len()).collect::<Vec<_>>();
+        expected.sort_by(|&left, &right| {
+            field
+                .get_datum(left)
+                .unwrap()
+                .cmp(&field.get_datum(right).unwrap())
+        });
+        expected
+    }
+
+    #[test]
+    fn reductions() {
+        let len = CHUNK_SIZE * 3 + 17;
+        let field = sample_field(len);
+        let values = field.to_value_vec();
+
+        let (sum, sum_sq) = sequential_sums(&values);
+        assert_eq!(field.sum(), sum);
+        assert_eq!(field.sum_sq(), sum_sq);
+        assert_eq!(
+            field.num_na(),
+            values.iter().filter(|value| !value.exists()).count()
+        );
+        assert_eq!(field.num_na() + field.num_exists(), len);
+
+        // chunked folds are identical to the sequential fold
+        let counts = |data: &FieldData<f64>| {
+            fold_chunks(
+                data,
+                || (0usize, 0usize),
+                |(na, exists), value| {
+                    if value.exists() {
+                        (na, exists + 1)
+                    } else {
+                        (na + 1, exists)
+                    }
+                },
+                |(na, exists), (chunk_na, chunk_exists)| (na + chunk_na, exists + chunk_exists),
+            )
+        };
+        let expected = values.iter().fold((0, 0), |(na, exists), value| {
+            if value.exists() {
+                (na, exists + 1)
+            } else {
+                (na + 1, exists)
+            }
+        });
+        assert_eq!(counts(&field), expected);
+
+        let bools = values
+            .iter()
+            .map(|value| value.map(|value| value > 1.0))
+            .collect::<FieldData<bool>>();
+        assert!(bools.any());
+        assert!(!bools.all());
+    }
+
+    #[test]
+    fn sort_order() {
+        let len = CHUNK_SIZE * 4 + 5;
+        let field = sample_field(len)
+            .iter()
+            .map(|value| value.map(|&value| value as u64))
+            .collect::<FieldData<u64>>();
+        assert_eq!(field.sort_order(), expected_sort_order(&field));
+    }
+
+    #[test]
+    fn non_sync_fields() {
+        use std::rc::Rc;
+
+        use error;
+        use stats::{Mean, Variance};
+
+        // fields which can't be shared between threads still implement `DataIndex`, and support
+        // the statistics which don't require sharing them
+        #[derive(Debug)]
+        struct RcField(Rc<FieldData<f64>>);
+        impl DataIndex for RcField {
+            type DType = f64;
+            fn get_datum(&self, idx: usize) -> error::Result<Value<&f64>> {
+                self.0.get_datum(idx)
+            }
+            fn len(&self) -> usize {
+                self.0.len()
+            }
+        }
+
+        let field = RcField(Rc::new(FieldData::from_vec(vec![1.0, 2.0, 6.0])));
+        assert_eq!(field.sum(), 9.0);
+        assert_eq!(field.sum_sq(), 41.0);
+        assert_eq!(field.mean(), 3.0);
+        assert_eq!(field.var(), 7.0);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn thread_pools() {
+        // results are the same regardless of the number of threads available
+        let len = CHUNK_SIZE * 5 + 3;
+        let field = sample_field(len);
+        let values = field.to_value_vec();
+        let (sum, sum_sq) = sequential_sums(&values);
+        let num_na = values.iter().filter(|value| !value.exists()).count();
+        let int_field = field
+            .iter()
+            .map(|value| value.map(|&value| value as u64))
+            .collect::<FieldData<u64>>();
+        let expected_order = expected_sort_order(&int_field);
+        for nthreads in 1..5 {
+            let pool = ::rayon::ThreadPoolBuilder::new()
+                .num_threads(nthreads)
+                .build()
+                .unwrap();
+            pool.install(|| {
+                assert_eq!(field.sum(), sum);
+                assert_eq!(field.sum_sq(), sum_sq);
+                assert_eq!(field.num_na(), num_na);
+                assert_eq!(int_field.sort_order(), expected_order);
+            });
+        }
+    }
+}
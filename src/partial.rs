@@ -12,6 +12,9 @@ This module should be unnecessary once
 */
 use std::marker::PhantomData;
 
+#[cfg(feature = "parallel")]
+use rayon;
+
 use access::DataIndex;
 use cons::*;
 use fieldlist::*;
@@ -107,6 +110,75 @@ where
     }
 }
 
+/// Trait for applying a partially-implemented function [Func](trait.Func.html) to a cons-list,
+/// where fields are processed in parallel (on the global `rayon` thread pool) when the `parallel`
+/// feature is enabled. Each field is processed with a new function object, which are then merged
+/// (in field order) into the provided function object, so the results are identical to those of
+/// [PartialMap](trait.PartialMap.html). Without the `parallel` feature, this is equivalent to
+/// `PartialMap::map`.
+pub trait ParallelPartialMap<F>: PartialMap<F> {
+    /// Apply the function `F` to the values in this cons-list, processing fields in parallel.
+    fn par_map(&self, f: &mut F) -> Self::Output;
+}
+#[cfg(not(feature = "parallel"))]
+impl<T, F> ParallelPartialMap<F> for T
+where
+    T: PartialMap<F>,
+    F: MergeFunc,
+{
+    fn par_map(&self, f: &mut F) -> Self::Output {
+        self.map(f)
+    }
+}
+#[cfg(feature = "parallel")]
+impl<F> ParallelPartialMap<F> for Nil
+where
+    F: MergeFunc,
+{
+    fn par_map(&self, _f: &mut F) -> Nil {
+        Nil
+    }
+}
+#[cfg(feature = "parallel")]
+impl<Label, DType, DI, Fun, Tail, F> ParallelPartialMap<F>
+    for StorageCapabilitiesCons<Label, DType, DI, Fun, Implemented, Tail>
+where
+    Tail: ParallelPartialMap<F> + Sync,
+    Tail::Output: Send,
+    F: Func<DType> + MergeFunc + Send,
+    F::Output: Send,
+    DI: DataIndex<DType = DType> + Sync,
+{
+    fn par_map(&self, f: &mut F) -> Self::Output {
+        let data = &self.head.value_ref().data;
+        let tail = &self.tail;
+        let mut head_f = F::default();
+        let mut tail_f = F::default();
+        let (head, tail) = rayon::join(|| head_f.call(data), || tail.par_map(&mut tail_f));
+        f.merge(head_f);
+        f.merge(tail_f);
+        FieldPayloadCons {
+            head: TypedValue::from(head).into(),
+            tail,
+        }
+    }
+}
+#[cfg(feature = "parallel")]
+impl<Label, DType, DI, Fun, Tail, F> ParallelPartialMap<F>
+    for StorageCapabilitiesCons<Label, DType, DI, Fun, Unimplemented, Tail>
+where
+    Tail: ParallelPartialMap<F>,
+    DI: DataIndex<DType = DType>,
+    F: FuncDefault + MergeFunc,
+{
+    fn par_map(&self, f: &mut F) -> Self::Output {
+        FieldPayloadCons {
+            head: TypedValue::from(f.call()).into(),
+            tail: self.tail.par_map(f),
+        }
+    }
+}
+
 /// Implementation of a function for a particular data type.
 pub trait Func<DType> {
     /// Output of this function.
@@ -117,6 +189,13 @@ pub trait Func<DType> {
         DI: DataIndex<DType = DType>;
 }
 
+/// Trait for functions whose state, accumulated while being called on separate fields, can be
+/// merged. Required by [ParallelPartialMap](trait.ParallelPartialMap.html).
+pub trait MergeFunc: Default {
+    /// Merge the state of `other` (which was called on later fields) into this function.
+    fn merge(&mut self, other: Self);
+}
+
 /// Default function implementation with no valid implementation exists.
 pub trait FuncDefault {
     /// Output of this function.
@@ -1,90 +1,1118 @@
+/*!
+Traits, structs, and implementations for reshaping [DataView](../view/struct.DataView.html)s.
+
+# Grouping
+
+A `DataView` can be grouped by the unique (composite) values of one or more of its fields using
+the [group_by](../view/struct.DataView.html#method.group_by) method. Aggregations (such as
+[AggSum](struct.AggSum.html) or [AggMean](struct.AggMean.html)) can then be computed for each
+group, resulting in a new `DataView` with one record per group.
+
+# Melting
+
+A 'wide' `DataView` (for example, one with a separate field for each year of observations) can be
+converted to a 'long' `DataView` using the [melt](../view/struct.DataView.html#method.melt)
+method, which stacks a set of same-typed fields into a single value field alongside a field
+containing the original field names.
+
+# Pivoting
+
+The reverse operation, converting a 'long' `DataView` to a 'wide' `DataView`, is performed by the
+[pivot](../view/struct.DataView.html#method.pivot) method. Since field labels are types, the
+fields of the resulting `DataView` are specified by a list of target labels, and matched to the
+values of the variable field by label name.
+*/
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
 use std::marker::PhantomData;
+use std::ops::{Add, Mul};
+
+use num_traits::{AsPrimitive, Zero};
 
-use apply::{FieldReduceFn, ApplyFieldReduce, Select, ReduceDataIndex, CompositeUnique};
-use view::{DataView, IntoFieldList};
-use field::FieldIdent;
-use store::DataStore;
+use access::DataIndex;
+use cons::*;
 use error::*;
+use field::{FieldData, Value};
+use join::JoinIntoStore;
+use label::*;
+use par::MaybeSync;
+use select::{SelectFieldByLabel, SelectFieldList};
+use stats::*;
+use store::{DataStore, IntoView, NRows, PushBackField};
+use view::*;
+
+/// A [DataIndex](../access/trait.DataIndex.html) providing access to a subset of the records of
+/// another `DataIndex`, such as a single group of a [GroupBy](struct.GroupBy.html).
+#[derive(Debug)]
+pub struct GroupIndex<'a, DI: 'a> {
+    data: &'a DI,
+    indices: &'a [usize],
+}
+impl<'a, DI> GroupIndex<'a, DI> {
+    /// Create a new `GroupIndex` of the records of `data` at the provided `indices`.
+    pub fn new(data: &'a DI, indices: &'a [usize]) -> GroupIndex<'a, DI> {
+        GroupIndex { data, indices }
+    }
+}
+impl<'a, DI> DataIndex for GroupIndex<'a, DI>
+where
+    DI: DataIndex,
+{
+    type DType = DI::DType;
+
+    fn get_datum(&self, idx: usize) -> Result<Value<&DI::DType>> {
+        match self.indices.get(idx) {
+            Some(&data_idx) => self.data.get_datum(data_idx),
+            None => Err(AgnesError::IndexError {
+                index: idx,
+                len: self.indices.len(),
+            }),
+        }
+    }
+    fn len(&self) -> usize {
+        self.indices.len()
+    }
+}
+
+/// A [DataView](../view/struct.DataView.html) with its records grouped by the unique
+/// (composite) values of the fields labeled by `ByLabels`. Created by the
+/// [group_by](../view/struct.DataView.html#method.group_by) method.
+pub struct GroupBy<Labels, Frames, ByLabels> {
+    view: DataView<Labels, Frames>,
+    groups: Vec<Vec<usize>>,
+    _by: PhantomData<ByLabels>,
+}
 
-// struct AggregateFn<'a, F> where F: FieldMapFn {
-//     target_ds: &'a mut DataStore,
-//     func: F
-// }
-// impl<'a, 'b, F> FieldReduceFn<'a> for AggregateFn<'b, F> where F: FieldMapFn {
-//     type Output = Result<DataView>;
-
-//     fn reduce(&mut self, fields: Vec<ReduceDataIndex<'a>>) -> Result<DataView> {
-
-//         // let mut store = DataSt
-//     }
-// }
-pub trait Aggregate {
-    fn aggregate<Ids: IntoFieldList, By: IntoFieldList, F, FOut>(&self, group_by: By,
-        fields: Ids, func: F) -> Result<DataView>
-        where F: Fn(&DataView, &FieldIdent) -> FOut;
-}
-impl Aggregate for DataView {
-    fn aggregate<Ids: IntoFieldList, By: IntoFieldList, F, FOut>(&self, group_by: By,
-        fields: Ids, func: F)-> Result<DataView>
-        where F: Fn(&DataView, &FieldIdent) -> FOut
+impl<Labels, Frames> DataView<Labels, Frames>
+where
+    Frames: Clone + NRows,
+{
+    /// Groups the records of this `DataView` by the unique (composite) values of the fields
+    /// labeled by `ByLabels`. Groups are ordered by their first appearance in this `DataView`.
+    /// Missing (NA) values are grouped together.
+    ///
+    /// Fields referenced by `ByLabels` must implement `Hash`.
+    pub fn group_by<ByLabels>(&self) -> GroupBy<Labels, Frames, ByLabels>
+    where
+        Labels: HasLabels<ByLabels>,
+        Self: SelectFieldList<ByLabels>,
+        <Self as SelectFieldList<ByLabels>>::Output: HashIndex + PartialEqIndex,
     {
-        let group_by = group_by.into_field_list();
-        let mut grouped_keys = self.composite_unique(group_by.clone())?;
-        println!("{}", grouped_keys);
-        struct AggregateFn<'a, FOut> {
-            phantom: PhantomData<FOut>,
-            source: &'a DataView,
+        let fl = self.field_list::<ByLabels>();
+        let mut group_indices = HashMap::new();
+        let mut groups: Vec<Vec<usize>> = vec![];
+        for i in 0..self.nrows() {
+            let group_idx = *group_indices.entry(Record::new(&fl, i)).or_insert_with(|| {
+                groups.push(vec![]);
+                groups.len() - 1
+            });
+            groups[group_idx].push(i);
+        }
+        GroupBy {
+            view: DataView::new(self.frames.clone()),
+            groups,
+            _by: PhantomData,
         }
-        impl<'a, 'b, FOut> FieldReduceFn<'a> for AggregateFn<'b, FOut> {
-            type Output = FOut;
-            fn reduce(&mut self, fields: Vec<ReduceDataIndex<'a>>) -> Self::Output {
-                debug_assert!(fields.len() > 0);
-                for i in 0..grouped_keys.len() {
-                    for field in fields {
-                        field.sasdjfiaoj();
-                    }
+    }
+}
+
+/// Type alias for the `DataStore` containing the group key fields of a
+/// [GroupBy](struct.GroupBy.html).
+pub type GroupKeyStore<Labels, Frames, ByLabels> =
+    <Frames as JoinIntoStore<<Labels as LabelSubset<ByLabels>>::Output, DataStore<Nil>>>::Output;
+
+/// Type alias for the `DataView` resulting from computing the aggregations `Aggs` over a
+/// [GroupBy](struct.GroupBy.html).
+pub type AggregatedView<Labels, Frames, ByLabels, Aggs> = <<Aggs as AggregateInto<
+    DataView<Labels, Frames>,
+    GroupKeyStore<Labels, Frames, ByLabels>,
+>>::Output as IntoView>::Output;
+
+impl<Labels, Frames, ByLabels> GroupBy<Labels, Frames, ByLabels> {
+    /// Returns the number of groups.
+    pub fn ngroups(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Returns the indices of the records (within the grouped `DataView`) in each group.
+    pub fn groups(&self) -> &[Vec<usize>] {
+        &self.groups
+    }
+
+    /// Computes the aggregations in the cons-list `Aggs` (which can be generated with the
+    /// [Aggregations](../macro.Aggregations.html) macro) for each group. Returns a new `DataView`
+    /// with one record per group, containing the group key fields (labeled by `ByLabels`) followed
+    /// by one field for each aggregation.
+    pub fn aggregate<Aggs>(&self) -> Result<AggregatedView<Labels, Frames, ByLabels, Aggs>>
+    where
+        Labels: LabelSubset<ByLabels>,
+        Frames: JoinIntoStore<<Labels as LabelSubset<ByLabels>>::Output, DataStore<Nil>>,
+        Aggs: AggregateInto<DataView<Labels, Frames>, GroupKeyStore<Labels, Frames, ByLabels>>,
+        <Aggs as AggregateInto<
+            DataView<Labels, Frames>,
+            GroupKeyStore<Labels, Frames, ByLabels>,
+        >>::Output: IntoView,
+    {
+        // the first record of each group supplies the values for the key fields
+        let key_indices = self.groups.iter().map(|group| group[0]).collect::<Vec<_>>();
+        let store = JoinIntoStore::<<Labels as LabelSubset<ByLabels>>::Output, _>::join_into_store(
+            &self.view.frames,
+            DataStore::<Nil>::empty(),
+            &key_indices,
+        )?;
+        Ok(Aggs::aggregate_into(&self.view, &self.groups, store).into_view())
+    }
+}
+
+/// Trait for an aggregation which computes a single value for each group of records of `View`.
+pub trait Aggregation<View> {
+    /// Label of the field resulting from this aggregation.
+    type OutLabel;
+    /// Data type of the field resulting from this aggregation.
+    type OutDType;
+
+    /// Computes this aggregation for each group (list of record indices) in `groups`.
+    fn aggregate(view: &View, groups: &[Vec<usize>]) -> FieldData<Self::OutDType>;
+}
+
+/// Trait for computing a cons-list of [Aggregation](trait.Aggregation.html)s over groups of
+/// records of `View`, adding the results to `Store`.
+pub trait AggregateInto<View, Store> {
+    /// The output `DataStore` after adding all aggregation fields.
+    type Output;
+
+    /// Computes the aggregations over `groups` and adds them as fields to `store`.
+    fn aggregate_into(view: &View, groups: &[Vec<usize>], store: Store) -> Self::Output;
+}
+impl<View, Store> AggregateInto<View, Store> for Nil {
+    type Output = Store;
+
+    fn aggregate_into(_view: &View, _groups: &[Vec<usize>], store: Store) -> Store {
+        store
+    }
+}
+impl<View, Store, Head, Tail> AggregateInto<View, Store> for Cons<Head, Tail>
+where
+    Head: Aggregation<View>,
+    Store: PushBackField<Head::OutLabel, Head::OutDType>,
+    Tail: AggregateInto<
+        View,
+        DataStore<<Store as PushBackField<Head::OutLabel, Head::OutDType>>::OutputFields>,
+    >,
+{
+    type Output = <Tail as AggregateInto<
+        View,
+        DataStore<<Store as PushBackField<Head::OutLabel, Head::OutDType>>::OutputFields>,
+    >>::Output;
+
+    fn aggregate_into(view: &View, groups: &[Vec<usize>], store: Store) -> Self::Output {
+        let store = store.push_back_field(Head::aggregate(view, groups));
+        Tail::aggregate_into(view, groups, store)
+    }
+}
+
+/// Create a cons-list of [Aggregation](reshape/trait.Aggregation.html)s for use with
+/// [GroupBy::aggregate](reshape/struct.GroupBy.html#method.aggregate).
+///
+/// # Example
+/// `let dv_agg = dv.group_by::<Labels![Region]>().aggregate::<Aggregations![AggSum<Gdp, TotalGdp>,
+/// AggCount<Gdp, NumCountries>]>()?;`
+#[macro_export]
+macro_rules! Aggregations {
+    () => { $crate::cons::Nil };
+    ($agg:ty $(, $rest:ty)* $(,)*) => {
+        $crate::cons::Cons<$agg, Aggregations![$($rest),*]>
+    };
+}
+
+macro_rules! declare_aggregations {
+    ($($(#[$doc:meta])* $name:ident;)*) => {$(
+        $(#[$doc])*
+        pub struct $name<Label, OutLabel = Label> {
+            _marker: PhantomData<(Label, OutLabel)>,
+        }
+    )*}
+}
+
+declare_aggregations![
+    /// Aggregation computing the sum of the values of field `Label` for each group, stored in a
+    /// field labeled `OutLabel`. Missing values are ignored; the sum of a group with no existing
+    /// values is missing.
+    AggSum;
+    /// Aggregation computing the arithmetic mean of the values of field `Label` for each group,
+    /// stored in a field labeled `OutLabel`. Missing values are ignored; the mean of a group with
+    /// no existing values is missing.
+    AggMean;
+    /// Aggregation counting the number of existing (non-missing) values of field `Label` for each
+    /// group, stored in a field labeled `OutLabel`.
+    AggCount;
+    /// Aggregation computing the minimum value of field `Label` for each group, stored in a field
+    /// labeled `OutLabel`. The minimum of a group with no existing values is missing.
+    AggMin;
+    /// Aggregation computing the maximum value of field `Label` for each group, stored in a field
+    /// labeled `OutLabel`. The maximum of a group with no existing values is missing.
+    AggMax;
+    /// Aggregation computing the sample standard deviation of the values of field `Label` for
+    /// each group, stored in a field labeled `OutLabel`. Missing values are ignored; the standard
+    /// deviation of a group with fewer than two existing values is missing.
+    AggStDev;
+    /// Aggregation counting the number of missing (NA) values of field `Label` for each group,
+    /// stored in a field labeled `OutLabel`.
+    AggNumNa;
+    /// Aggregation selecting the first existing (non-missing) value of field `Label` for each
+    /// group, stored in a field labeled `OutLabel`. The first value of a group with no existing
+    /// values is missing.
+    AggFirst;
+];
+
+impl<View, Label, OutLabel> Aggregation<View> for AggSum<Label, OutLabel>
+where
+    View: SelectFieldByLabel<Label>,
+    VFieldOf<View, Label>: MaybeSync,
+    VFieldTypeOf<View, Label>: for<'a> Add<&'a VFieldTypeOf<View, Label>, Output = VFieldTypeOf<View, Label>>
+        + Zero
+        + Debug
+        + Default,
+{
+    type OutLabel = OutLabel;
+    type OutDType = VFieldTypeOf<View, Label>;
+
+    fn aggregate(view: &View, groups: &[Vec<usize>]) -> FieldData<Self::OutDType> {
+        let field = view.select_field();
+        groups
+            .iter()
+            .map(|group| {
+                let group = GroupIndex::new(&field, group);
+                if group.num_exists() == 0 {
+                    Value::Na
+                } else {
+                    Value::Exists(group.sum())
                 }
-            }
+            })
+            .collect()
+    }
+}
+
+impl<View, Label, OutLabel> Aggregation<View> for AggMean<Label, OutLabel>
+where
+    View: SelectFieldByLabel<Label>,
+    VFieldOf<View, Label>: MaybeSync,
+    VFieldTypeOf<View, Label>: for<'a> Add<&'a VFieldTypeOf<View, Label>, Output = VFieldTypeOf<View, Label>>
+        + Zero
+        + AsPrimitive<f64>,
+{
+    type OutLabel = OutLabel;
+    type OutDType = f64;
+
+    fn aggregate(view: &View, groups: &[Vec<usize>]) -> FieldData<f64> {
+        let field = view.select_field();
+        groups
+            .iter()
+            .map(|group| {
+                let group = GroupIndex::new(&field, group);
+                if group.num_exists() == 0 {
+                    Value::Na
+                } else {
+                    Value::Exists(group.mean())
+                }
+            })
+            .collect()
+    }
+}
+
+impl<View, Label, OutLabel> Aggregation<View> for AggCount<Label, OutLabel>
+where
+    View: SelectFieldByLabel<Label>,
+    VFieldOf<View, Label>: MaybeSync,
+{
+    type OutLabel = OutLabel;
+    type OutDType = usize;
+
+    fn aggregate(view: &View, groups: &[Vec<usize>]) -> FieldData<usize> {
+        let field = view.select_field();
+        groups
+            .iter()
+            .map(|group| Value::Exists(GroupIndex::new(&field, group).num_exists()))
+            .collect()
+    }
+}
+
+impl<View, Label, OutLabel> Aggregation<View> for AggMin<Label, OutLabel>
+where
+    View: SelectFieldByLabel<Label>,
+    VFieldTypeOf<View, Label>: PartialOrd + Clone + Debug + Default,
+{
+    type OutLabel = OutLabel;
+    type OutDType = VFieldTypeOf<View, Label>;
+
+    fn aggregate(view: &View, groups: &[Vec<usize>]) -> FieldData<Self::OutDType> {
+        let field = view.select_field();
+        groups
+            .iter()
+            .map(|group| match GroupIndex::new(&field, group).min() {
+                Some(min) => Value::Exists(min.clone()),
+                None => Value::Na,
+            })
+            .collect()
+    }
+}
+
+impl<View, Label, OutLabel> Aggregation<View> for AggMax<Label, OutLabel>
+where
+    View: SelectFieldByLabel<Label>,
+    VFieldTypeOf<View, Label>: PartialOrd + Clone + Debug + Default,
+{
+    type OutLabel = OutLabel;
+    type OutDType = VFieldTypeOf<View, Label>;
+
+    fn aggregate(view: &View, groups: &[Vec<usize>]) -> FieldData<Self::OutDType> {
+        let field = view.select_field();
+        groups
+            .iter()
+            .map(|group| match GroupIndex::new(&field, group).max() {
+                Some(max) => Value::Exists(max.clone()),
+                None => Value::Na,
+            })
+            .collect()
+    }
+}
+
+impl<View, Label, OutLabel> Aggregation<View> for AggStDev<Label, OutLabel>
+where
+    View: SelectFieldByLabel<Label>,
+    VFieldOf<View, Label>: MaybeSync,
+    VFieldTypeOf<View, Label>: for<'a> Add<&'a VFieldTypeOf<View, Label>, Output = VFieldTypeOf<View, Label>>
+        + Zero
+        + AsPrimitive<f64>,
+    for<'a, 'b> &'a VFieldTypeOf<View, Label>:
+        Mul<&'b VFieldTypeOf<View, Label>, Output = VFieldTypeOf<View, Label>>,
+{
+    type OutLabel = OutLabel;
+    type OutDType = f64;
+
+    fn aggregate(view: &View, groups: &[Vec<usize>]) -> FieldData<f64> {
+        let field = view.select_field();
+        groups
+            .iter()
+            .map(|group| {
+                let group = GroupIndex::new(&field, group);
+                if group.num_exists() < 2 {
+                    Value::Na
+                } else {
+                    Value::Exists(group.stdev())
+                }
+            })
+            .collect()
+    }
+}
+
+impl<View, Label, OutLabel> Aggregation<View> for AggNumNa<Label, OutLabel>
+where
+    View: SelectFieldByLabel<Label>,
+    VFieldOf<View, Label>: MaybeSync,
+{
+    type OutLabel = OutLabel;
+    type OutDType = usize;
+
+    fn aggregate(view: &View, groups: &[Vec<usize>]) -> FieldData<usize> {
+        let field = view.select_field();
+        groups
+            .iter()
+            .map(|group| Value::Exists(GroupIndex::new(&field, group).num_na()))
+            .collect()
+    }
+}
+
+impl<View, Label, OutLabel> Aggregation<View> for AggFirst<Label, OutLabel>
+where
+    View: SelectFieldByLabel<Label>,
+    VFieldTypeOf<View, Label>: Debug + Default + Clone,
+{
+    type OutLabel = OutLabel;
+    type OutDType = VFieldTypeOf<View, Label>;
+
+    fn aggregate(view: &View, groups: &[Vec<usize>]) -> FieldData<Self::OutDType> {
+        let field = view.select_field();
+        groups
+            .iter()
+            .map(|group| {
+                match GroupIndex::new(&field, group)
+                    .iter()
+                    .find(|value| value.exists())
+                {
+                    Some(first) => first.cloned(),
+                    None => Value::Na,
+                }
+            })
+            .collect()
+    }
+}
+
+impl<Labels, Frames> DataView<Labels, Frames>
+where
+    Frames: NRows,
+{
+    /// Melts (unpivots) this `DataView` from a 'wide' format to a 'long' format. The fields
+    /// labeled by `ValueLabels` (which must all have the same data type) are stacked into a single
+    /// field labeled `ValueLabel`, alongside a `String` field labeled `VarLabel` containing the
+    /// name of the field each value originated from. The fields labeled by `IdLabels` are
+    /// repeated for each of the value fields.
+    ///
+    /// The resulting `DataView` has `nrows() * N` records (where `N` is the number of labels in
+    /// `ValueLabels`): all records for the first value field, followed by all records for the
+    /// second value field, and so on.
+    pub fn melt<IdLabels, ValueLabels, VarLabel, ValueLabel>(
+        &self,
+    ) -> Result<MeltedView<Labels, Frames, IdLabels, ValueLabels, VarLabel, ValueLabel>>
+    where
+        Labels: HasLabels<IdLabels> + HasLabels<ValueLabels>,
+        IdLabels: SubsetStore<Labels, Frames>,
+        ValueLabels: StrLabels
+            + MeltInto<
+                DataView<Labels, Frames>,
+                <IdLabels as SubsetStore<Labels, Frames>>::Output,
+                VarLabel,
+                ValueLabel,
+            >,
+        MeltedStore<Labels, Frames, IdLabels, ValueLabels, VarLabel, ValueLabel>: IntoView,
+    {
+        let nrows = self.nrows();
+        let id_indices = (0..ValueLabels::labels().len())
+            .flat_map(|_| 0..nrows)
+            .collect::<Vec<_>>();
+        let store = IdLabels::subset_store(&self.frames, &id_indices)?;
+        Ok(ValueLabels::melt_into(self, nrows, store).into_view())
+    }
+}
+
+/// Type alias for the `DataStore` resulting from melting a `DataView`.
+pub type MeltedStore<Labels, Frames, IdLabels, ValueLabels, VarLabel, ValueLabel> =
+    <ValueLabels as MeltInto<
+        DataView<Labels, Frames>,
+        <IdLabels as SubsetStore<Labels, Frames>>::Output,
+        VarLabel,
+        ValueLabel,
+    >>::Output;
+/// Type alias for the `DataView` resulting from melting a `DataView`.
+pub type MeltedView<Labels, Frames, IdLabels, ValueLabels, VarLabel, ValueLabel> =
+    <MeltedStore<Labels, Frames, IdLabels, ValueLabels, VarLabel, ValueLabel> as IntoView>::Output;
+
+/// Trait for creating a new `DataStore` containing the fields of `Frames` labeled by the labels
+/// in the `Self` label cons-list (as looked up in the label lookup list `Labels`).
+pub trait SubsetStore<Labels, Frames> {
+    /// The resulting `DataStore`.
+    type Output;
+
+    /// Creates a new `DataStore` from the selected fields of `frames`, using the provided
+    /// permutation indices.
+    fn subset_store(frames: &Frames, permutation: &[usize]) -> Result<Self::Output>;
+}
+impl<LabelList, Labels, Frames> SubsetStore<Labels, Frames> for LabelList
+where
+    Labels: LabelSubset<LabelList>,
+    Frames: JoinIntoStore<<Labels as LabelSubset<LabelList>>::Output, DataStore<Nil>>,
+{
+    type Output = <Frames as JoinIntoStore<
+        <Labels as LabelSubset<LabelList>>::Output,
+        DataStore<Nil>,
+    >>::Output;
+
+    fn subset_store(frames: &Frames, permutation: &[usize]) -> Result<Self::Output> {
+        JoinIntoStore::<<Labels as LabelSubset<LabelList>>::Output, _>::join_into_store(
+            frames,
+            DataStore::<Nil>::empty(),
+            permutation,
+        )
+    }
+}
+
+/// Trait for adding the variable name (labeled `VarLabel`) and value (labeled `ValueLabel`) fields
+/// resulting from melting the fields of `View` labeled by the `Self` label cons-list to `Store`.
+pub trait MeltInto<View, Store, VarLabel, ValueLabel> {
+    /// The output `DataStore` after adding the variable name and value fields.
+    type Output;
+
+    /// Adds the variable name and value fields to `store`, where `nrows` is the number of records
+    /// in `view`.
+    fn melt_into(view: &View, nrows: usize, store: Store) -> Self::Output;
+}
+impl<ValueLabels, View, Store, VarLabel, ValueLabel> MeltInto<View, Store, VarLabel, ValueLabel>
+    for ValueLabels
+where
+    ValueLabels: StrLabels + MeltValues<View>,
+    Store: PushBackField<VarLabel, String>,
+    DataStore<<Store as PushBackField<VarLabel, String>>::OutputFields>:
+        PushBackField<ValueLabel, <ValueLabels as MeltValues<View>>::DType>,
+{
+    type Output = DataStore<
+        <DataStore<<Store as PushBackField<VarLabel, String>>::OutputFields> as PushBackField<
+            ValueLabel,
+            <ValueLabels as MeltValues<View>>::DType,
+        >>::OutputFields,
+    >;
+
+    fn melt_into(view: &View, nrows: usize, store: Store) -> Self::Output {
+        let vars = ValueLabels::labels()
+            .iter()
+            .flat_map(|name| (0..nrows).map(move |_| name.to_string()))
+            .collect::<Vec<_>>();
+        let store = store.push_back_field(vars.into());
+
+        let mut values = FieldData::default();
+        ValueLabels::melt_values(view, &mut values);
+        store.push_back_field(values)
+    }
+}
+
+/// Trait for collecting the values of the fields of `View` labeled by the `Self` label cons-list
+/// into a single field. All fields must have the same data type.
+pub trait MeltValues<View> {
+    /// Common data type of the fields labeled by `Self`.
+    type DType;
+
+    /// Appends the values of each field labeled by `Self` (in order) to `values`.
+    fn melt_values(view: &View, values: &mut FieldData<Self::DType>);
+}
+impl<View, Label> MeltValues<View> for LCons<Label, Nil>
+where
+    View: SelectFieldByLabel<Label>,
+    VFieldTypeOf<View, Label>: Debug + Default + Clone,
+{
+    type DType = VFieldTypeOf<View, Label>;
+
+    fn melt_values(view: &View, values: &mut FieldData<Self::DType>) {
+        for value in view.select_field().iter() {
+            values.push_ref(value);
+        }
+    }
+}
+impl<View, Label, NextLabel, Tail> MeltValues<View> for LCons<Label, LCons<NextLabel, Tail>>
+where
+    View: SelectFieldByLabel<Label>,
+    VFieldTypeOf<View, Label>: Debug + Default + Clone,
+    LCons<NextLabel, Tail>: MeltValues<View, DType = VFieldTypeOf<View, Label>>,
+{
+    type DType = VFieldTypeOf<View, Label>;
+
+    fn melt_values(view: &View, values: &mut FieldData<Self::DType>) {
+        for value in view.select_field().iter() {
+            values.push_ref(value);
         }
-        let grouped_key_selections = group_by.iter().map(|ident| grouped_keys.select(ident))
+        LCons::<NextLabel, Tail>::melt_values(view, values);
+    }
+}
+
+impl<Labels, Frames> DataView<Labels, Frames>
+where
+    Frames: Clone + NRows,
+{
+    /// Pivots (casts) this `DataView` from a 'long' format to a 'wide' format, the inverse of
+    /// [melt](struct.DataView.html#method.melt). Records are grouped by the unique (composite)
+    /// values of the fields labeled by `KeyLabels`, resulting in one record per group. For each
+    /// label in the `Targets` label cons-list, a field is added containing the values for the
+    /// records in each group whose `VarLabel`-labeled field matches the target label's name.
+    ///
+    /// The values of each target field are computed using the [Aggregation](
+    /// ../reshape/trait.Aggregation.html) `Agg`, which allows for handling of multiple records
+    /// with the same key and variable (e.g. [AggSum](../reshape/struct.AggSum.html)).
+    /// [AggFirst](../reshape/struct.AggFirst.html) can be used when no duplicates are expected.
+    /// The output label of `Agg` is unused and can be omitted (e.g. `AggSum<Amount>`).
+    /// Combinations of key and variable which don't exist in this `DataView` are missing (NA) in
+    /// the result; combinations whose values are all missing are aggregated by `Agg` as usual
+    /// (e.g. missing for `AggSum`, `0` for `AggCount`). Records whose variable is missing or
+    /// doesn't match the name of any label in `Targets` are ignored.
+    pub fn pivot<KeyLabels, VarLabel, Targets, Agg>(
+        &self,
+    ) -> Result<PivotedView<Labels, Frames, KeyLabels, Targets, Agg>>
+    where
+        Labels: HasLabels<KeyLabels>,
+        Self: SelectFieldList<KeyLabels>,
+        <Self as SelectFieldList<KeyLabels>>::Output: HashIndex + PartialEqIndex,
+        KeyLabels: SubsetStore<Labels, Frames>,
+        Self: SelectFieldByLabel<VarLabel>,
+        VFieldTypeOf<Self, VarLabel>: Display,
+        Targets: PivotInto<Self, <KeyLabels as SubsetStore<Labels, Frames>>::Output, Agg>,
+        PivotedStore<Labels, Frames, KeyLabels, Targets, Agg>: IntoView,
+    {
+        let grouped = self.group_by::<KeyLabels>();
+        let key_indices = grouped
+            .groups()
+            .iter()
+            .map(|group| group[0])
             .collect::<Vec<_>>();
-        grouped_key_selections
-            .apply_field_reduce(&mut AggregateFn {
-                phantom: PhantomData::<FOut>,
-                source: self
-            });
-        // for ident in fields.into_field_list() {
+        let store = KeyLabels::subset_store(&self.frames, &key_indices)?;
 
-            // ident.asdjfiaosdj();
-        // }
-        let mut store = DataStore::empty();
-        Ok(store.into())
+        let vars = SelectFieldByLabel::<VarLabel>::select_field(self)
+            .iter()
+            .map(|var| var.map(|var| var.to_string()))
+            .collect::<Vec<_>>();
+        Ok(Targets::pivot_into(self, grouped.groups(), &vars, store).into_view())
     }
 }
 
-struct MeltFn {
+/// Type alias for the `DataStore` resulting from pivoting a `DataView`.
+pub type PivotedStore<Labels, Frames, KeyLabels, Targets, Agg> = <Targets as PivotInto<
+    DataView<Labels, Frames>,
+    <KeyLabels as SubsetStore<Labels, Frames>>::Output,
+    Agg,
+>>::Output;
+/// Type alias for the `DataView` resulting from pivoting a `DataView`.
+pub type PivotedView<Labels, Frames, KeyLabels, Targets, Agg> =
+    <PivotedStore<Labels, Frames, KeyLabels, Targets, Agg> as IntoView>::Output;
+
+/// Trait for adding the pivoted fields labeled by the labels in the `Self` label cons-list to
+/// `Store`, using the aggregation `Agg` to compute the values of each field from the records of
+/// `View`.
+pub trait PivotInto<View, Store, Agg> {
+    /// The output `DataStore` after adding the pivoted fields.
+    type Output;
 
+    /// Computes the pivoted fields and adds them to `store`. `groups` contains the indices of the
+    /// records of `view` in each group, and `vars` contains the variable name of each record.
+    fn pivot_into(
+        view: &View,
+        groups: &[Vec<usize>],
+        vars: &[Value<String>],
+        store: Store,
+    ) -> Self::Output;
+}
+impl<View, Store, Agg> PivotInto<View, Store, Agg> for Nil {
+    type Output = Store;
+
+    fn pivot_into(
+        _view: &View,
+        _groups: &[Vec<usize>],
+        _vars: &[Value<String>],
+        store: Store,
+    ) -> Store {
+        store
+    }
 }
-struct CastFn {
+impl<View, Store, Agg, Target, Tail> PivotInto<View, Store, Agg> for LCons<Target, Tail>
+where
+    Target: LabelName,
+    Agg: Aggregation<View>,
+    Agg::OutDType: Debug + Default + Clone,
+    Store: PushBackField<Target, Agg::OutDType>,
+    Tail: PivotInto<
+        View,
+        DataStore<<Store as PushBackField<Target, Agg::OutDType>>::OutputFields>,
+        Agg,
+    >,
+{
+    type Output = <Tail as PivotInto<
+        View,
+        DataStore<<Store as PushBackField<Target, Agg::OutDType>>::OutputFields>,
+        Agg,
+    >>::Output;
 
+    fn pivot_into(
+        view: &View,
+        groups: &[Vec<usize>],
+        vars: &[Value<String>],
+        store: Store,
+    ) -> Self::Output {
+        let name = Target::name();
+        let cells = groups
+            .iter()
+            .map(|group| {
+                group
+                    .iter()
+                    .cloned()
+                    .filter(|&idx| match vars[idx] {
+                        Value::Exists(ref var) => var.as_str() == name,
+                        Value::Na => false,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let values = Agg::aggregate(view, &cells);
+        let data = cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| {
+                if cell.is_empty() {
+                    Value::Na
+                } else {
+                    values.get(i).unwrap()
+                }
+            })
+            .collect::<FieldData<_>>();
+        Tail::pivot_into(view, groups, vars, store.push_back_field(data))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use select::FieldSelect;
     use test_utils::*;
-    use apply::NumNa;
+
+    namespace![
+        @continue(typenum::Add1<::test_utils::dept_table::Table>)
+        table dept_stats {
+            TotalSalaryOffset: i64,
+            NumEmps: usize,
+            MinEmpId: u64,
+            MaxEmpId: u64,
+            MeanVacationHrs: f64,
+            StDevVacationHrs: f64,
+            NumNaDeptId: usize,
+        }
+        table emp_long {
+            Variable: String,
+            Id: u64,
+        }
+        table sales_long {
+            Region: String,
+            Quarter: String,
+            Amount: u64,
+        }
+        table sales_wide {
+            Q1: u64,
+            Q2: u64,
+            Q3: u64,
+        }
+    ];
+
+    #[test]
+    fn group_by_aggregate() {
+        let dv = sample_merged_emp_table();
+        let grouped = dv.group_by::<Labels![emp_table::DeptId]>();
+        assert_eq!(grouped.ngroups(), 4);
+        assert_eq!(
+            grouped.groups(),
+            &[vec![0, 2, 3], vec![1], vec![4], vec![5, 6]][..]
+        );
+
+        let dv_agg = grouped
+            .aggregate::<Aggregations![
+                AggSum<extra_emp::SalaryOffset, dept_stats::TotalSalaryOffset>,
+                AggCount<emp_table::EmpId, dept_stats::NumEmps>,
+                AggMin<emp_table::EmpId, dept_stats::MinEmpId>,
+                AggMax<emp_table::EmpId, dept_stats::MaxEmpId>,
+                AggMean<extra_emp::VacationHrs, dept_stats::MeanVacationHrs>,
+                AggStDev<extra_emp::VacationHrs, dept_stats::StDevVacationHrs>,
+            ]>()
+            .unwrap();
+        assert_eq!(dv_agg.nrows(), 4);
+        assert_eq!(dv_agg.nfields(), 7);
+        assert_eq!(
+            dv_agg.field::<emp_table::DeptId>().to_vec(),
+            vec![1u64, 2, 3, 4]
+        );
+        assert_eq!(
+            dv_agg.field::<dept_stats::TotalSalaryOffset>().to_vec(),
+            vec![-26i64, 4, 10, -1]
+        );
+        assert_eq!(
+            dv_agg.field::<dept_stats::NumEmps>().to_vec(),
+            vec![3usize, 1, 1, 2]
+        );
+        assert_eq!(
+            dv_agg.field::<dept_stats::MinEmpId>().to_vec(),
+            vec![0u64, 2, 8, 9]
+        );
+        assert_eq!(
+            dv_agg.field::<dept_stats::MaxEmpId>().to_vec(),
+            vec![6u64, 2, 8, 10]
+        );
+        let means = dv_agg.field::<dept_stats::MeanVacationHrs>().to_value_vec();
+        let expected_means = [(47.3 + 98.3 + 12.2) / 3.0, 54.1, -1.2, (5.4 + 22.5) / 2.0];
+        for (mean, expected) in means.iter().zip(expected_means.iter()) {
+            match *mean {
+                Value::Exists(mean) => assert!((mean - expected).abs() < 1e-4),
+                Value::Na => panic!("unexpected missing mean"),
+            }
+        }
+        let stdevs = dv_agg.field::<dept_stats::StDevVacationHrs>();
+        assert!(stdevs.get_datum(0).unwrap().exists());
+        assert!(stdevs.get_datum(1).unwrap().is_na());
+        assert!(stdevs.get_datum(2).unwrap().is_na());
+        match stdevs.get_datum(3).unwrap() {
+            Value::Exists(&stdev) => assert!((stdev - 12.0915).abs() < 1e-3),
+            Value::Na => panic!("unexpected missing stdev"),
+        }
+    }
 
     #[test]
-    fn aggregate() {
-        // let orig_dv = sample_merged_emp_table();
-        // println!("{}", orig_dv);
-        // let aggregated = orig_dv.aggregate(["EmpId", "EmpName"], ["DeptId", "DidTraining"],
-        //     NumNaFn {});
-
-        let orig_dv: DataView = generate_sample_random_datastore(50, 0).into();
-        println!("{}", orig_dv);
-        let aggregated = orig_dv.aggregate(["col1", "col2"], ["col3", "col4"],
-            |dv, ident| dv.num_na(ident));
+    fn group_by_na() {
+        let store: emp_table::Store = emp_table_from_field![
+            FieldData::from(vec![0u64, 1, 2, 3, 4, 5]),
+            FieldData::from_field_vec(vec![
+                Value::Exists(1u64),
+                Value::Na,
+                Value::Exists(1),
+                Value::Na,
+                Value::Exists(2),
+                Value::Na,
+            ]),
+            FieldData::from(
+                ["Sally", "Jamie", "Bob", "Cara", "Louis", "Louise"]
+                    .iter()
+                    .map(|s| s.to_string())
+                    .collect::<Vec<_>>()
+            )
+        ];
+
+        let dv_agg = store
+            .into_view()
+            .group_by::<Labels![emp_table::DeptId]>()
+            .aggregate::<Aggregations![
+                AggCount<emp_table::DeptId, dept_stats::NumEmps>,
+                AggNumNa<emp_table::DeptId, dept_stats::NumNaDeptId>,
+                AggMin<emp_table::EmpId, dept_stats::MinEmpId>,
+            ]>()
+            .unwrap();
+        assert_eq!(dv_agg.nrows(), 3);
+        assert_eq!(
+            dv_agg.field::<emp_table::DeptId>().to_value_vec(),
+            vec![Value::Exists(1u64), Value::Na, Value::Exists(2)]
+        );
+        assert_eq!(
+            dv_agg.field::<dept_stats::NumEmps>().to_vec(),
+            vec![2usize, 0, 1]
+        );
+        assert_eq!(
+            dv_agg.field::<dept_stats::NumNaDeptId>().to_vec(),
+            vec![0usize, 3, 0]
+        );
+        assert_eq!(
+            dv_agg.field::<dept_stats::MinEmpId>().to_vec(),
+            vec![0u64, 1, 4]
+        );
+    }
+
+    #[test]
+    fn group_by_multiple() {
+        let dv = sample_merged_emp_table();
+        let dv_agg = dv
+            .group_by::<Labels![extra_emp::DidTraining, emp_table::DeptId]>()
+            .aggregate::<Aggregations![AggCount<emp_table::EmpId, dept_stats::NumEmps>]>()
+            .unwrap();
+        assert_eq!(dv_agg.nrows(), 6);
+        assert_eq!(dv_agg.nfields(), 3);
+        assert_eq!(
+            dv_agg.field::<emp_table::DeptId>().to_vec(),
+            vec![1u64, 2, 1, 3, 4, 4]
+        );
+        assert_eq!(
+            dv_agg.field::<extra_emp::DidTraining>().to_vec(),
+            vec![false, false, true, true, false, true]
+        );
+        assert_eq!(
+            dv_agg.field::<dept_stats::NumEmps>().to_vec(),
+            vec![1usize, 1, 2, 1, 1, 1]
+        );
+    }
+
+    #[test]
+    fn melt() {
+        let dv = sample_emp_table().into_view();
+        let dv_long = dv
+            .melt::<
+                Labels![emp_table::EmpName],
+                Labels![emp_table::EmpId, emp_table::DeptId],
+                emp_long::Variable,
+                emp_long::Id,
+            >()
+            .unwrap();
+        assert_eq!(dv_long.nrows(), 14);
+        assert_eq!(dv_long.fieldnames(), vec!["EmpName", "Variable", "Id"]);
+        assert_eq!(
+            dv_long.field::<emp_table::EmpName>().to_vec(),
+            vec![
+                "Sally", "Jamie", "Bob", "Cara", "Louis", "Louise", "Ann", "Sally", "Jamie", "Bob",
+                "Cara", "Louis", "Louise", "Ann",
+            ]
+        );
+        assert_eq!(
+            dv_long.field::<emp_long::Variable>().to_vec(),
+            ["EmpId"; 7]
+                .iter()
+                .chain(["DeptId"; 7].iter())
+                .map(|s| s.to_string())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            dv_long.field::<emp_long::Id>().to_vec(),
+            vec![0u64, 2, 5, 6, 8, 9, 10, 1, 2, 1, 1, 3, 4, 4]
+        );
+    }
+
+    #[test]
+    fn melt_na() {
+        let store: emp_table::Store = emp_table_from_field![
+            FieldData::from_field_vec(vec![Value::Exists(0u64), Value::Na, Value::Exists(2)]),
+            FieldData::from_field_vec(vec![Value::Na, Value::Exists(1u64), Value::Exists(1)]),
+            FieldData::from(vec!["Sally".to_string(), "Jamie".into(), "Bob".into()])
+        ];
+        // sort to check that melting respects the view's ordering
+        let mut dv = store.into_view();
+        dv.sort_by_label::<emp_table::EmpName>();
+        let dv_long = dv
+            .melt::<
+                Labels![emp_table::EmpName],
+                Labels![emp_table::DeptId, emp_table::EmpId],
+                emp_long::Variable,
+                emp_long::Id,
+            >()
+            .unwrap();
+        assert_eq!(dv_long.nrows(), 6);
+        assert_eq!(
+            dv_long.field::<emp_table::EmpName>().to_vec(),
+            vec!["Bob", "Jamie", "Sally", "Bob", "Jamie", "Sally"]
+        );
+        assert_eq!(
+            dv_long.field::<emp_long::Id>().to_value_vec(),
+            vec![
+                Value::Exists(1u64),
+                Value::Exists(1),
+                Value::Na,
+                Value::Exists(2),
+                Value::Na,
+                Value::Exists(0),
+            ]
+        );
+    }
+
+    #[test]
+    fn pivot() {
+        let dv = sample_emp_table().into_view();
+        let dv_long = dv
+            .melt::<
+                Labels![emp_table::EmpName],
+                Labels![emp_table::EmpId, emp_table::DeptId],
+                emp_long::Variable,
+                emp_long::Id,
+            >()
+            .unwrap();
+        // pivot back to the original shape
+        let dv_wide = dv_long
+            .pivot::<
+                Labels![emp_table::EmpName],
+                emp_long::Variable,
+                Labels![emp_table::EmpId, emp_table::DeptId],
+                AggFirst<emp_long::Id>,
+            >()
+            .unwrap();
+        assert_eq!(dv_wide.nrows(), 7);
+        assert_eq!(dv_wide.fieldnames(), vec!["EmpName", "EmpId", "DeptId"]);
+        assert_eq!(
+            dv_wide.field::<emp_table::EmpName>().to_vec(),
+            dv.field::<emp_table::EmpName>().to_vec(),
+        );
+        assert_eq!(
+            dv_wide.field::<emp_table::EmpId>().to_vec(),
+            dv.field::<emp_table::EmpId>().to_vec(),
+        );
+        assert_eq!(
+            dv_wide.field::<emp_table::DeptId>().to_vec(),
+            dv.field::<emp_table::DeptId>().to_vec(),
+        );
+    }
+
+    #[test]
+    fn pivot_duplicates_missing() {
+        let store: sales_long::Store = DataStore::<Nil>::empty()
+            .push_back_from_iter(
+                ["East", "East", "West", "East", "West"]
+                    .iter()
+                    .map(|s| s.to_string()),
+            )
+            .push_back_from_iter(["Q1", "Q1", "Q2", "Q2", "Q4"].iter().map(|s| s.to_string()))
+            .push_back_from_value_iter(vec![
+                Value::Exists(10u64),
+                Value::Exists(5),
+                Value::Exists(7),
+                Value::Na,
+                Value::Exists(1),
+            ]);
+        let dv = store.into_view();
+
+        let dv_sum = dv
+            .pivot::<
+                Labels![sales_long::Region],
+                sales_long::Quarter,
+                Labels![sales_wide::Q1, sales_wide::Q2, sales_wide::Q3],
+                AggSum<sales_long::Amount>,
+            >()
+            .unwrap();
+        assert_eq!(dv_sum.nrows(), 2);
+        // the West record for Q4 doesn't match any target label, so it is dropped
+        assert_eq!(dv_sum.fieldnames(), vec!["Region", "Q1", "Q2", "Q3"]);
+        assert_eq!(
+            dv_sum.field::<sales_long::Region>().to_vec(),
+            vec!["East", "West"]
+        );
+        assert_eq!(
+            dv_sum.field::<sales_wide::Q1>().to_value_vec(),
+            vec![Value::Exists(15u64), Value::Na]
+        );
+        // the only amount for East in Q2 is missing, so AggSum results in a missing sum
+        assert_eq!(
+            dv_sum.field::<sales_wide::Q2>().to_value_vec(),
+            vec![Value::Na, Value::Exists(7u64)]
+        );
+        assert_eq!(
+            dv_sum.field::<sales_wide::Q3>().to_value_vec(),
+            vec![Value::Na, Value::Na]
+        );
+
+        let dv_count = dv
+            .pivot::<
+                Labels![sales_long::Region],
+                sales_long::Quarter,
+                Labels![sales_wide::Q1, sales_wide::Q2, sales_wide::Q3],
+                AggCount<sales_long::Amount>,
+            >()
+            .unwrap();
+        assert_eq!(
+            dv_count.field::<sales_wide::Q1>().to_value_vec(),
+            vec![Value::Exists(2usize), Value::Na]
+        );
+        // AggCount counts no existing values for East in Q2, rather than resulting in NA
+        assert_eq!(
+            dv_count.field::<sales_wide::Q2>().to_value_vec(),
+            vec![Value::Exists(0usize), Value::Exists(1)]
+        );
+        assert_eq!(
+            dv_count.field::<sales_wide::Q3>().to_value_vec(),
+            vec![Value::Na, Value::Na]
+        );
+        // every existing amount is counted, except for the dropped Q4 record
+        let num_counted = dv_count
+            .field::<sales_wide::Q1>()
+            .to_vec()
+            .iter()
+            .sum::<usize>()
+            + dv_count
+                .field::<sales_wide::Q2>()
+                .to_vec()
+                .iter()
+                .sum::<usize>()
+            + dv_count
+                .field::<sales_wide::Q3>()
+                .to_vec()
+                .iter()
+                .sum::<usize>();
+        assert_eq!(num_counted, 3);
+
+        let dv_first = dv
+            .pivot::<
+                Labels![sales_long::Region],
+                sales_long::Quarter,
+                Labels![sales_wide::Q1, sales_wide::Q2],
+                AggFirst<sales_long::Amount>,
+            >()
+            .unwrap();
+        assert_eq!(
+            dv_first.field::<sales_wide::Q1>().to_value_vec(),
+            vec![Value::Exists(10u64), Value::Na]
+        );
+        assert_eq!(
+            dv_first.field::<sales_wide::Q2>().to_value_vec(),
+            vec![Value::Na, Value::Exists(7u64)]
+        );
     }
 }
@@ -2,6 +2,8 @@
 Traits for selecting a field from a data structure.
 */
 use access::DataIndex;
+use cons::{Cons, Nil};
+use label::LCons;
 
 /// Trait for accessing the data of a single field as a struct which implements
 /// [DataIndex](../access/trait.DataIndex.html).
@@ -29,12 +31,49 @@ pub trait SelectFieldByLabel<Label> {
     fn select_field(&self) -> Self::Output;
 }
 
+/// Trait implemented by data structures to provide access to data for a list of fields.
+/// `LabelList` is a [LabelCons](../label/type.LabelCons.html) list of labels, which can be
+/// generated using the [Labels](../label/macro.Labels.html) macro. For a
+/// [DataView](../view/struct.DataView.html), this is also available as
+/// [field_list](../view/struct.DataView.html#method.field_list).
+pub trait SelectFieldList<LabelList> {
+    /// Cons-list of fields (each implementing [DataIndex](../access/trait.DataIndex.html)), in the
+    /// same order as `LabelList`.
+    type Output;
+
+    /// Returns a cons-list of the fields specified by `LabelList`.
+    fn select_field_list(&self) -> Self::Output;
+}
+impl<T> SelectFieldList<Nil> for T {
+    type Output = Nil;
+
+    fn select_field_list(&self) -> Nil {
+        Nil
+    }
+}
+impl<T, Label, Tail> SelectFieldList<LCons<Label, Tail>> for T
+where
+    T: SelectFieldByLabel<Label> + SelectFieldList<Tail>,
+{
+    type Output =
+        Cons<<T as SelectFieldByLabel<Label>>::Output, <T as SelectFieldList<Tail>>::Output>;
+
+    fn select_field_list(&self) -> Self::Output {
+        Cons {
+            head: SelectFieldByLabel::<Label>::select_field(self),
+            tail: SelectFieldList::<Tail>::select_field_list(self),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::FieldSelect;
+    use super::{FieldSelect, SelectFieldList};
 
     use access::DataIndex;
+    use cons::{Cons, Nil};
     use field::Value;
+    use label::LCons;
 
     #[cfg(feature = "test-utils")]
     use test_utils::*;
@@ -53,4 +92,29 @@ mod tests {
             .collect::<Vec<_>>();
         assert_eq!(result, vec![1, 1, 1, 1, 1, 1, 1]);
     }
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn select_field_list() {
+        use test_utils::emp_table::*;
+        use test_utils::extra_emp::*;
+
+        let dv = sample_merged_emp_table();
+        let Cons {
+            head: names,
+            tail: Cons {
+                head: trained,
+                tail: Nil,
+            },
+        } = SelectFieldList::<LCons<EmpName, LCons<DidTraining, Nil>>>::select_field_list(&dv);
+        assert_eq!(names.len(), 7);
+        assert_eq!(
+            names.get_datum(2).unwrap(),
+            Value::Exists(&"Bob".to_string())
+        );
+        assert_eq!(
+            trained.to_vec(),
+            vec![false, false, true, true, true, false, true]
+        );
+    }
 }
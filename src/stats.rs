@@ -7,6 +7,7 @@ use num_traits::{AsPrimitive, Zero};
 
 use access::DataIndex;
 use field::*;
+use par::{fold_chunks, MaybeSync};
 
 /// A trait for counting NA and existing values in a field.
 pub trait NaCount {
@@ -18,22 +19,35 @@ pub trait NaCount {
 
 impl<DI> NaCount for DI
 where
-    DI: DataIndex,
+    DI: DataIndex + MaybeSync,
 {
     fn num_na(&self) -> usize {
-        self.iter().fold(
-            0usize,
+        fold_chunks(
+            self,
+            || 0usize,
             |count, value| if value.exists() { count } else { count + 1 },
+            |total, count| total + count,
         )
     }
     fn num_exists(&self) -> usize {
-        self.iter().fold(
-            0usize,
+        fold_chunks(
+            self,
+            || 0usize,
             |count, value| if value.exists() { count + 1 } else { count },
+            |total, count| total + count,
         )
     }
 }
 
+// counts the existing values of `data` on the calling thread, so that the statistics below are
+// available for fields which can't be shared between threads
+fn count_exists<DI>(data: &DI) -> usize
+where
+    DI: DataIndex,
+{
+    data.iter().filter(|value| value.exists()).count()
+}
+
 /// A trait for computing the sum of values in a field.
 pub trait Sum {
     /// The data type of the sum result.
@@ -69,11 +83,11 @@ pub trait Mean {
 
 impl<DI> Mean for DI
 where
-    DI: DataIndex + NaCount + Sum,
+    DI: DataIndex + Sum,
     <DI as Sum>::Output: AsPrimitive<f64>,
 {
     fn mean(&self) -> f64 {
-        let nexists = match self.num_exists() {
+        let nexists = match count_exists(self) {
             0 => {
                 return 0.0;
             }
@@ -133,11 +147,11 @@ pub trait Variance {
 
 impl<DI> Variance for DI
 where
-    DI: DataIndex + SumSq + NaCount + Mean,
+    DI: DataIndex + SumSq + Mean,
     <DI as SumSq>::Output: AsPrimitive<f64>,
 {
     fn var(&self) -> f64 {
-        let nexists = match self.num_exists() {
+        let nexists = match count_exists(self) {
             0 => {
                 return 0.0;
             }
@@ -148,7 +162,7 @@ where
         sum_sq.as_() / (nexists - 1.0) - nexists / (nexists - 1.0) * mean * mean
     }
     fn varp(&self) -> f64 {
-        let nexists = match self.num_exists() {
+        let nexists = match count_exists(self) {
             0 => {
                 return 0.0;
             }
@@ -179,9 +193,6 @@ where
     type Output = DI::DType;
 
     fn min(&self) -> Option<&DI::DType> {
-        if self.num_exists() == 0 {
-            return None;
-        }
         let mut ret = None;
         for val in self.iter() {
             match (ret, val) {
@@ -199,9 +210,6 @@ where
         ret
     }
     fn max(&self) -> Option<&DI::DType> {
-        if self.num_exists() == 0 {
-            return None;
-        }
         let mut ret = None;
         for val in self.iter() {
             match (ret, val) {
@@ -220,6 +228,38 @@ where
     }
 }
 
+/// A trait for reducing a boolean field to a single `bool`.
+pub trait AnyAll {
+    /// Returns `true` if any existing value in this field is `true`. Ignores missing values; if
+    /// all values are missing, returns `false`.
+    fn any(&self) -> bool;
+    /// Returns `true` if all existing values in this field are `true`. Ignores missing values; if
+    /// all values are missing, returns `true`.
+    fn all(&self) -> bool;
+}
+
+impl<DI> AnyAll for DI
+where
+    DI: DataIndex<DType = bool> + MaybeSync,
+{
+    fn any(&self) -> bool {
+        fold_chunks(
+            self,
+            || false,
+            |any, value| any || value == valref![true],
+            |total, any| total || any,
+        )
+    }
+    fn all(&self) -> bool {
+        fold_chunks(
+            self,
+            || true,
+            |all, value| all && value != valref![false],
+            |total, all| total && all,
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -441,4 +481,42 @@ mod tests {
             .into_view();
         assert_eq!(dv.field::<foo::Foo>().max(), None);
     }
+
+    #[test]
+    fn any_all() {
+        let dv = DataStore::<Nil>::empty()
+            .push_back_from_value_iter::<foo::Foo, _, _, _>(vec![
+                Value::Exists(false),
+                Value::Na,
+                Value::Exists(true),
+            ])
+            .into_view();
+        assert_eq!(dv.field::<foo::Foo>().any(), true);
+        assert_eq!(dv.field::<foo::Foo>().all(), false);
+
+        let dv = DataStore::<Nil>::empty()
+            .push_back_from_value_iter::<foo::Foo, _, _, _>(vec![
+                Value::Exists(true),
+                Value::Na,
+                Value::Exists(true),
+            ])
+            .into_view();
+        assert_eq!(dv.field::<foo::Foo>().any(), true);
+        assert_eq!(dv.field::<foo::Foo>().all(), true);
+
+        let dv = DataStore::<Nil>::empty()
+            .push_back_from_value_iter::<foo::Foo, _, _, _>(vec![
+                Value::Exists(false),
+                Value::Exists(false),
+            ])
+            .into_view();
+        assert_eq!(dv.field::<foo::Foo>().any(), false);
+        assert_eq!(dv.field::<foo::Foo>().all(), false);
+
+        let dv = DataStore::<Nil>::empty()
+            .push_back_from_value_iter::<foo::Foo, bool, _, _>(vec![Value::Na, Value::Na])
+            .into_view();
+        assert_eq!(dv.field::<foo::Foo>().any(), false);
+        assert_eq!(dv.field::<foo::Foo>().all(), true);
+    }
 }
@@ -4,7 +4,7 @@ data from a single data source.
 */
 use std::fmt::Debug;
 use std::ops::Deref;
-use std::rc::Rc;
+use std::sync::Arc;
 
 #[cfg(feature = "serialize")]
 use serde::ser::{Serialize, Serializer};
@@ -20,19 +20,19 @@ use label::*;
 use select::{FieldSelect, SelectFieldByLabel};
 use view::{DataView, FrameLookupCons, ViewFrameCons};
 
-/// Local `Rc` wrapper type for [FieldData](../field/struct.FieldData.html) objects.
+/// Local `Arc` wrapper type for [FieldData](../field/struct.FieldData.html) objects.
 #[derive(Debug, Hash, PartialEq, Eq)]
-pub struct DataRef<DType>(pub Rc<FieldData<DType>>);
+pub struct DataRef<DType>(pub Arc<FieldData<DType>>);
 
 impl<DType> DataRef<DType> {
     fn new(field: FieldData<DType>) -> DataRef<DType> {
-        DataRef(Rc::new(field))
+        DataRef(Arc::new(field))
     }
 }
 
 impl<DType> Clone for DataRef<DType> {
     fn clone(&self) -> DataRef<DType> {
-        DataRef(Rc::clone(&self.0))
+        DataRef(Arc::clone(&self.0))
     }
 }
 
@@ -46,7 +46,7 @@ impl<T> Deref for DataRef<T> {
 
 impl<T> From<FieldData<T>> for DataRef<T> {
     fn from(orig: FieldData<T>) -> DataRef<T> {
-        DataRef(Rc::new(orig))
+        DataRef(Arc::new(orig))
     }
 }
 
@@ -13,12 +13,15 @@ object with all of the records of the two source `DataView`s.
 parameters.
 
 */
+use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::collections::HashSet;
 #[cfg(test)]
 use std::collections::VecDeque;
 use std::fmt::{self, Display, Formatter};
 use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
 
 use prettytable as pt;
 #[cfg(feature = "serialize")]
@@ -29,13 +32,13 @@ use error;
 use frame::{DataFrame, Framed};
 
 use cons::*;
-use field::Value;
+use field::{FieldData, Value};
 use fieldlist::FieldPayloadCons;
 use join::*;
 use label::*;
 use partial::{DeriveCapabilities, Func, FuncDefault, Implemented, IsImplemented, PartialMap};
-use select::{FieldSelect, SelectFieldByLabel};
-use store::{AssocStorage, NRows};
+use select::{FieldSelect, SelectFieldByLabel, SelectFieldList};
+use store::{AssocStorage, DataStore, IntoView, NRows, PushBackClonedFromValueIter, PushBackField};
 
 /// Cons-list of `DataFrame`s held by a `DataView. `FrameIndex` is simply an index used by
 /// `FrameLookupCons` to look up `DataFrame`s for a specified `Label`, and `FrameFields` is
@@ -76,12 +79,12 @@ pub trait FrameDetails {
     /// The associated frame index.
     type FrameIndex: Identifier;
     /// The associated `Label` within the frame.
-    type FrameLabel: Label;
+    type FrameLabel: Identifier;
 }
 impl<FrameIndex, FrameLabel> FrameDetails for FrameDetailMarkers<FrameIndex, FrameLabel>
 where
     FrameIndex: Identifier,
-    FrameLabel: Label,
+    FrameLabel: Identifier,
 {
     type FrameIndex = FrameIndex;
     type FrameLabel = FrameLabel;
@@ -112,7 +115,7 @@ impl<Labels, Frames> DataView<Labels, Frames> {
 
 impl<Labels, Frames> DataView<Labels, Frames> {
     /// Field names in this data view
-    pub fn fieldnames<'a>(&'a self) -> Vec<&'a str>
+    pub fn fieldnames(&self) -> Vec<Cow<'static, str>>
     where
         Labels: StrLabels,
     {
@@ -214,6 +217,130 @@ where
     }
 }
 
+impl<Labels, Frames> DataView<Labels, Frames>
+where
+    Frames: NRows,
+{
+    /// Materializes this `DataView` into a new `DataStore`, copying the values of all of the
+    /// fields of this `DataView` (in the current permutation order, after any filtering or
+    /// sorting) into newly-allocated storage. Returns a new `DataView` of that `DataStore`.
+    ///
+    /// The resulting `DataView` has a single frame and no longer references the `DataStore`s
+    /// underlying this `DataView`, so those can be released once this `DataView` is dropped.
+    pub fn materialize(
+        &self,
+    ) -> error::Result<
+        <<Frames as JoinIntoStore<Labels, DataStore<Nil>>>::Output as IntoView>::Output,
+    >
+    where
+        Frames: JoinIntoStore<Labels, DataStore<Nil>>,
+        <Frames as JoinIntoStore<Labels, DataStore<Nil>>>::Output: IntoView,
+    {
+        let permutation = (0..self.nrows()).collect::<Vec<_>>();
+        let store = JoinIntoStore::<Labels, _>::join_into_store(
+            &self.frames,
+            DataStore::<Nil>::empty(),
+            &permutation,
+        )?;
+        Ok(store.into_view())
+    }
+
+    /// Appends the records of `other` to the records of this `DataView`, returning a new
+    /// `DataView` of a newly-created `DataStore` containing the records of both. `other` must have
+    /// the same fields as this `DataView`. Fields are created in the order of this `DataView`, with
+    /// missing values preserved.
+    pub fn concat(
+        &self,
+        other: &Self,
+    ) -> <<Frames as ConcatIntoStore<Labels, DataStore<Nil>>>::Output as IntoView>::Output
+    where
+        Frames: ConcatIntoStore<Labels, DataStore<Nil>>,
+        <Frames as ConcatIntoStore<Labels, DataStore<Nil>>>::Output: IntoView,
+    {
+        Self::concat_all(vec![self, other])
+    }
+
+    /// Appends the records of all the `DataView`s in `views` (in iteration order), returning a new
+    /// `DataView` of a newly-created `DataStore` containing the records of all of them. Fields are
+    /// created in the view order of the `DataView` type, with missing values preserved.
+    pub fn concat_all<'a, I>(
+        views: I,
+    ) -> <<Frames as ConcatIntoStore<Labels, DataStore<Nil>>>::Output as IntoView>::Output
+    where
+        I: IntoIterator<Item = &'a Self>,
+        Self: 'a,
+        Frames: ConcatIntoStore<Labels, DataStore<Nil>>,
+        <Frames as ConcatIntoStore<Labels, DataStore<Nil>>>::Output: IntoView,
+    {
+        let frames = views
+            .into_iter()
+            .map(|view| &view.frames)
+            .collect::<Vec<_>>();
+        ConcatIntoStore::<Labels, _>::concat_into_store(&frames, DataStore::<Nil>::empty())
+            .into_view()
+    }
+}
+
+/// A trait for augmenting type `Store` (a [DataStore](../store/struct.DataStore.html)) with
+/// fields (as labeled by `Labels`) built by concatenating the data from a list of this
+/// [ViewFrameCons](type.ViewFrameCons.html) type.
+pub trait ConcatIntoStore<Labels, Store> {
+    /// The output type after augmenting `Store`.
+    type Output;
+
+    /// Augments `store` with the concatenated data of each of the `Labels` fields in `frames`.
+    fn concat_into_store(frames: &[&Self], store: Store) -> Self::Output;
+}
+impl<Frames, Store> ConcatIntoStore<Nil, Store> for Frames {
+    type Output = Store;
+    fn concat_into_store(_frames: &[&Self], store: Store) -> Store {
+        store
+    }
+}
+impl<Label, FrameIndex, FrameLabel, Tail, Frames, Store>
+    ConcatIntoStore<FrameLookupCons<Label, FrameIndex, FrameLabel, Tail>, Store> for Frames
+where
+    Frames: LookupValuedElemByLabel<FrameIndex>,
+    FrameByFrameIndexOf<Frames, FrameIndex>: SelectFieldByLabel<FrameLabel>,
+    Store: PushBackClonedFromValueIter<
+        Label,
+        FieldTypeFromFrameDetailsOf<Frames, FrameIndex, FrameLabel>,
+    >,
+    Frames: ConcatIntoStore<
+        Tail,
+        DataStore<
+            <Store as PushBackClonedFromValueIter<
+                Label,
+                FieldTypeFromFrameDetailsOf<Frames, FrameIndex, FrameLabel>,
+            >>::OutputFields,
+        >,
+    >,
+{
+    type Output = <Frames as ConcatIntoStore<
+        Tail,
+        DataStore<
+            <Store as PushBackClonedFromValueIter<
+                Label,
+                FieldTypeFromFrameDetailsOf<Frames, FrameIndex, FrameLabel>,
+            >>::OutputFields,
+        >,
+    >>::Output;
+
+    fn concat_into_store(frames: &[&Self], store: Store) -> Self::Output {
+        let fields = frames
+            .iter()
+            .map(|frames| {
+                SelectFieldByLabel::<FrameLabel>::select_field(
+                    LookupValuedElemByLabel::<FrameIndex>::elem(*frames).value_ref(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let store =
+            store.push_back_cloned_from_value_iter(fields.iter().flat_map(|field| field.iter()));
+        ConcatIntoStore::<Tail, _>::concat_into_store(frames, store)
+    }
+}
+
 #[cfg(test)]
 pub trait StoreRefCounts {
     fn store_ref_counts(&self) -> VecDeque<usize>;
@@ -494,7 +621,7 @@ macro_rules! impl_addcell_is_impl {
         }
     )*}
 }
-impl_addcell_is_impl![String f64 f32 u64 u32 i64 i32 bool];
+impl_addcell_is_impl![String f64 f32 u64 u32 usize i64 i32 bool];
 
 impl<Labels, Frames> DataView<Labels, Frames> {
     /// Construct a new `DataView` with the label `CurrLabel` relabeled with the label `NewLabel`.
@@ -560,6 +687,171 @@ where
     >;
 }
 
+impl<Labels, Frames> DataView<Labels, Frames>
+where
+    Frames: Clone,
+{
+    /// Construct a new `DataView` with the field labeled `Label` removed. Fails to compile if this
+    /// `DataView` doesn't contain `Label`.
+    ///
+    /// A field can be replaced by removing it and then adding new data under the same label with
+    /// [with_field](struct.DataView.html#method.with_field).
+    pub fn without<Label>(&self) -> DataView<<Labels as RemoveLabel<Label>>::Output, Frames>
+    where
+        Labels: RemoveLabel<Label>,
+    {
+        DataView {
+            _labels: PhantomData,
+            frames: self.frames.clone(),
+        }
+    }
+}
+
+/// Trait for removing the label `TargetLabel` from a label lookup list.
+pub trait RemoveLabel<TargetLabel> {
+    /// The output type after removing `TargetLabel`.
+    type Output;
+}
+
+impl<TargetLabel, Label, FrameIndex, FrameLabel, Tail> RemoveLabel<TargetLabel>
+    for FrameLookupCons<Label, FrameIndex, FrameLabel, Tail>
+where
+    TargetLabel: LabelEq<Label>,
+    FrameLookupCons<Label, FrameIndex, FrameLabel, Tail>:
+        RemoveLabelMatch<TargetLabel, <TargetLabel as LabelEq<Label>>::Eq>,
+{
+    type Output = <FrameLookupCons<Label, FrameIndex, FrameLabel, Tail> as RemoveLabelMatch<
+        TargetLabel,
+        <TargetLabel as LabelEq<Label>>::Eq,
+    >>::Output;
+}
+
+/// Helper trait for removing a label. Used by [RemoveLabel](trait.RemoveLabel.html).
+/// `TargetLabel` is the label to remove, and `Match` is whether or not `TargetLabel` matches the
+/// head label in this type.
+pub trait RemoveLabelMatch<TargetLabel, Match> {
+    /// The output type after removing `TargetLabel`.
+    type Output;
+}
+// TargetLabel == Label, remove head
+impl<TargetLabel, Label, FrameIndex, FrameLabel, Tail> RemoveLabelMatch<TargetLabel, True>
+    for FrameLookupCons<Label, FrameIndex, FrameLabel, Tail>
+{
+    type Output = Tail;
+}
+// TargetLabel != Label, recurse
+impl<TargetLabel, Label, FrameIndex, FrameLabel, Tail> RemoveLabelMatch<TargetLabel, False>
+    for FrameLookupCons<Label, FrameIndex, FrameLabel, Tail>
+where
+    Tail: RemoveLabel<TargetLabel>,
+{
+    type Output =
+        FrameLookupCons<Label, FrameIndex, FrameLabel, <Tail as RemoveLabel<TargetLabel>>::Output>;
+}
+
+/// Trait for converting field data into a single-field `DataView` of a field labeled `Label`.
+pub trait IntoFieldView<Label> {
+    /// The resultant single-field `DataView` type.
+    type Output;
+
+    /// Converts this field data into a single-field `DataView`.
+    fn into_field_view(self) -> Self::Output;
+}
+impl<Label, DType> IntoFieldView<Label> for FieldData<DType>
+where
+    DataStore<Nil>: PushBackField<Label, DType>,
+    DataStore<<DataStore<Nil> as PushBackField<Label, DType>>::OutputFields>: IntoView,
+{
+    type Output =
+        <DataStore<<DataStore<Nil> as PushBackField<Label, DType>>::OutputFields> as IntoView>::Output;
+
+    fn into_field_view(self) -> Self::Output {
+        DataStore::<Nil>::empty().push_back_field(self).into_view()
+    }
+}
+
+/// Type alias for a single-field `DataView` of a field with label `Label` and data type `DType`.
+pub type SingleFieldView<Label, DType> = <FieldData<DType> as IntoFieldView<Label>>::Output;
+
+/// Type alias for the `DataView` resulting from adding a field with label `NewLabel` and data type
+/// `DType` to the `DataView` `View`.
+pub type WithFieldView<View, NewLabel, DType> =
+    <View as ViewMerge<SingleFieldView<NewLabel, DType>>>::Output;
+
+/// Type alias for the data type of the field computed by the function `F` from the fields labeled
+/// by `LabelList` in the `DataView` `View`.
+pub type ComputedTypeOf<View, LabelList, F> =
+    <<View as SelectFieldList<LabelList>>::Output as ComputeRecords<F>>::Output;
+
+/// Type alias for the data type of the field computed by the function `F` from the (possibly
+/// missing) values of the fields labeled by `LabelList` in the `DataView` `View`.
+pub type ComputedValueTypeOf<View, LabelList, F> =
+    <<View as SelectFieldList<LabelList>>::Output as ComputeRecordValues<F>>::Output;
+
+impl<Labels, Frames> DataView<Labels, Frames> {
+    /// Construct a new `DataView` with all the fields of this `DataView` along with a new field
+    /// labeled `NewLabel` containing the data in `data`. The new field is held in a new frame.
+    ///
+    /// `data` must have one value for each record of this `DataView` (in the current filtered and
+    /// sorted order); fails if the length of `data` doesn't match `nrows()`. Fails to compile if
+    /// this `DataView` already contains `NewLabel`.
+    pub fn with_field<NewLabel, DType>(
+        &self,
+        data: FieldData<DType>,
+    ) -> error::Result<WithFieldView<Self, NewLabel, DType>>
+    where
+        FieldData<DType>: IntoFieldView<NewLabel>,
+        Self: ViewMerge<SingleFieldView<NewLabel, DType>>,
+    {
+        ViewMerge::merge(self, &data.into_field_view())
+    }
+
+    /// Construct a new `DataView` with all the fields of this `DataView` along with a new field
+    /// labeled `NewLabel`, computed by calling `f` on the values of multiple fields of each record.
+    /// `LabelList` is a [LabelCons](../label/type.LabelCons.html) list of labels (which can be
+    /// generated using the [Labels](../label/macro.Labels.html) macro), and `f` is called with one
+    /// `&T` argument for each label in `LabelList` (in the same order), where `T` is the type of
+    /// the labeled field. For example,
+    /// `dv.compute::<Labels![Gdp, Pop], GdpPerCapita, _>(|gdp: &f64, pop: &u64| gdp / *pop as f64)`.
+    /// Up to eight fields are supported.
+    ///
+    /// If any of the values of a record are missing, `f` isn't called and the computed value is
+    /// missing. Use [compute_values](struct.DataView.html#method.compute_values) to handle missing
+    /// values differently.
+    pub fn compute<LabelList, NewLabel, F>(
+        &self,
+        f: F,
+    ) -> error::Result<WithFieldView<Self, NewLabel, ComputedTypeOf<Self, LabelList, F>>>
+    where
+        Self: SelectFieldList<LabelList>,
+        <Self as SelectFieldList<LabelList>>::Output: ComputeRecords<F>,
+        FieldData<ComputedTypeOf<Self, LabelList, F>>: IntoFieldView<NewLabel>,
+        Self: ViewMerge<SingleFieldView<NewLabel, ComputedTypeOf<Self, LabelList, F>>>,
+    {
+        let data = SelectFieldList::<LabelList>::select_field_list(self).compute_records(f);
+        self.with_field::<NewLabel, _>(data)
+    }
+
+    /// Construct a new `DataView` with all the fields of this `DataView` along with a new field
+    /// labeled `NewLabel`, computed by calling `f` on the (possibly missing) values of multiple
+    /// fields of each record. Like [compute](struct.DataView.html#method.compute), except that
+    /// `f` is called for every record with one `Value<&T>` argument for each label in `LabelList`,
+    /// and returns a `Value` (which can be `Value::Na`).
+    pub fn compute_values<LabelList, NewLabel, F>(
+        &self,
+        f: F,
+    ) -> error::Result<WithFieldView<Self, NewLabel, ComputedValueTypeOf<Self, LabelList, F>>>
+    where
+        Self: SelectFieldList<LabelList>,
+        <Self as SelectFieldList<LabelList>>::Output: ComputeRecordValues<F>,
+        FieldData<ComputedValueTypeOf<Self, LabelList, F>>: IntoFieldView<NewLabel>,
+        Self: ViewMerge<SingleFieldView<NewLabel, ComputedValueTypeOf<Self, LabelList, F>>>,
+    {
+        let data = SelectFieldList::<LabelList>::select_field_list(self).compute_record_values(f);
+        self.with_field::<NewLabel, _>(data)
+    }
+}
+
 /// Trait for merging the data from two [DataView](struct.DataView.html)s into one new `DataView`.
 /// The two `DataView`s should have the same number of rows, and the resultant `DataView` is one
 /// with all the fields of both of the two original `DataView`s.
@@ -615,11 +907,12 @@ impl<Labels, Frames> DataView<Labels, Frames> {
     /// a subset of records from the two source `DataView`s according to the join parameters.
     ///
     /// Note that since this is creating a new `DataStore` object, it will be allocated new data to
-    /// store the contents of the joined `DataView`s.
+    /// store the contents of the joined `DataView`s. If either `DataView` is empty, the result is
+    /// an empty `DataView` containing the fields of both sides.
     pub fn join<Join, RLabels, RFrames>(
         &self,
         right: &DataView<RLabels, RFrames>,
-    ) -> <Self as SortMergeJoin<RLabels, RFrames, Join>>::Output
+    ) -> error::Result<<Self as SortMergeJoin<RLabels, RFrames, Join>>::Output>
     where
         Self: SortMergeJoin<RLabels, RFrames, Join>,
     {
@@ -634,6 +927,24 @@ impl<Labels, Frames> DataView<Labels, Frames> {
         //     }
         // }
     }
+
+    /// Combine two `DataView` objects using an as-of (nearest-key) join, matching each record of
+    /// this `DataView` with the record of `right` with the nearest key in the direction specified
+    /// by the [AsOf](../join/struct.AsOf.html) predicate in `Join`. If `tolerance` is provided,
+    /// records are only matched when their keys are at most `tolerance` apart.
+    ///
+    /// As with [join](struct.DataView.html#method.join), this creates a new `DataStore` object to
+    /// hold the contents of the joined `DataView`s.
+    pub fn join_asof<Join, RLabels, RFrames>(
+        &self,
+        right: &DataView<RLabels, RFrames>,
+        tolerance: Option<<Self as AsOfJoin<RLabels, RFrames, Join>>::Key>,
+    ) -> error::Result<<Self as AsOfJoin<RLabels, RFrames, Join>>::Output>
+    where
+        Self: AsOfJoin<RLabels, RFrames, Join>,
+    {
+        AsOfJoin::join_asof(self, right, tolerance)
+    }
 }
 
 /// Trait for updating the permutation of all data storage in a type.
@@ -654,6 +965,157 @@ where
     }
 }
 
+/// Trait for resetting the permutation of all data storage in a type, restoring the original
+/// order of the underlying data stores.
+pub trait ResetPermutation {
+    /// Reset the permutations, so that all records of the underlying data stores are included in
+    /// their original order.
+    fn reset_permutation(&mut self);
+    /// Returns the number of rows in each of the underlying data stores.
+    fn store_nrows(&self) -> Vec<usize>;
+    /// Returns the indices of the records in the underlying data store for each row, for each
+    /// of the underlying data stores.
+    fn store_row_ids(&self) -> Vec<Vec<usize>>;
+}
+impl ResetPermutation for Nil {
+    fn reset_permutation(&mut self) {}
+    fn store_nrows(&self) -> Vec<usize> {
+        vec![]
+    }
+    fn store_row_ids(&self) -> Vec<Vec<usize>> {
+        vec![]
+    }
+}
+impl<FrameIndex, FrameFields, Tail> ResetPermutation
+    for ViewFrameCons<FrameIndex, FrameFields, Tail>
+where
+    FrameFields: AssocStorage,
+    DataStore<FrameFields>: NRows,
+    Tail: ResetPermutation,
+{
+    fn reset_permutation(&mut self) {
+        self.head.value_mut().reset_permutation();
+        self.tail.reset_permutation();
+    }
+    fn store_nrows(&self) -> Vec<usize> {
+        let mut nrows = vec![self.head.value_ref().store_nrows()];
+        nrows.extend(self.tail.store_nrows());
+        nrows
+    }
+    fn store_row_ids(&self) -> Vec<Vec<usize>> {
+        let mut row_ids = vec![self.head.value_ref().row_ids()];
+        row_ids.extend(self.tail.store_row_ids());
+        row_ids
+    }
+}
+
+/// Trait for retrieving the index of the record in the underlying data store for each row of a
+/// frame.
+pub trait RowIds {
+    /// Returns the index of the record in the underlying data store for each row.
+    fn row_ids(&self) -> Vec<usize>;
+}
+impl<FrameFields> RowIds for DataFrame<FrameFields>
+where
+    FrameFields: AssocStorage,
+    DataStore<FrameFields>: NRows,
+{
+    fn row_ids(&self) -> Vec<usize> {
+        DataFrame::row_ids(self)
+    }
+}
+
+/// A saved record order of a `DataView`, which can be restored later with
+/// [restore_order](struct.DataView.html#method.restore_order).
+#[derive(Debug, Clone)]
+pub struct OrderCheckpoint<Frames> {
+    frames: Frames,
+}
+
+impl<Labels, Frames> DataView<Labels, Frames>
+where
+    Frames: ResetPermutation,
+{
+    /// Resets the record order of this `DataView`, undoing any filtering, sorting or slicing so
+    /// that all records of the underlying data stores are included in their original order.
+    ///
+    /// Returns a `DimensionMismatch` error (leaving this `DataView` unchanged) if the frames of
+    /// this `DataView` have underlying data stores of differing lengths (e.g. a filtered
+    /// `DataView` merged with a `DataView` of a smaller data store), or if the rows of the frames
+    /// currently refer to different records of their data stores (e.g. `DataView`s filtered
+    /// differently before being merged), since resetting the order would pair records which do
+    /// not belong together.
+    pub fn reset_order(&mut self) -> error::Result<()> {
+        let store_nrows = self.frames.store_nrows();
+        if store_nrows.windows(2).any(|nrows| nrows[0] != nrows[1]) {
+            return Err(error::AgnesError::DimensionMismatch(format!(
+                "unable to reset order of frames with data stores of differing lengths: {:?}",
+                store_nrows
+            )));
+        }
+        let store_row_ids = self.frames.store_row_ids();
+        if store_row_ids
+            .windows(2)
+            .any(|row_ids| row_ids[0] != row_ids[1])
+        {
+            return Err(error::AgnesError::DimensionMismatch(
+                "unable to reset order of frames whose rows refer to different records".into(),
+            ));
+        }
+        self.frames.reset_permutation();
+        Ok(())
+    }
+}
+
+impl<Labels, Frames> DataView<Labels, Frames> {
+    /// Returns the index of the record in the underlying data store for each row of the frame
+    /// containing the field labeled `Label`, in the current order of this `DataView`. This can be
+    /// used to trace each row of a filtered or sorted `DataView` back to its source record.
+    pub fn frame_row_ids<Label>(&self) -> Vec<usize>
+    where
+        Labels: FindFrameDetails<Label>,
+        Frames: FindFrame<Labels, Label>,
+        FrameOf<Frames, Labels, Label>: RowIds,
+    {
+        LookupValuedElemByLabel::<FrameIndexOf<Labels, Label>>::elem(&self.frames)
+            .value_ref()
+            .row_ids()
+    }
+
+    /// Saves the current record order of this `DataView`, which can be restored with
+    /// [restore_order](struct.DataView.html#method.restore_order) after further filtering or
+    /// sorting. No data is copied.
+    pub fn order_checkpoint(&self) -> OrderCheckpoint<Frames>
+    where
+        Frames: Clone,
+    {
+        OrderCheckpoint {
+            frames: self.frames.clone(),
+        }
+    }
+
+    /// Restores the record order of this `DataView` saved in `checkpoint` (created with
+    /// [order_checkpoint](struct.DataView.html#method.order_checkpoint)).
+    pub fn restore_order(&mut self, checkpoint: OrderCheckpoint<Frames>) {
+        self.frames = checkpoint.frames;
+    }
+}
+
+impl<Labels, FrameIndex, FrameFields> DataView<Labels, ViewFrameCons<FrameIndex, FrameFields, Nil>>
+where
+    FrameFields: AssocStorage,
+    DataStore<FrameFields>: NRows,
+{
+    /// Returns the index of the record in the underlying data store for each row of this
+    /// (single-frame) `DataView`, in the current order of this `DataView`. This can be used to
+    /// trace each row of a filtered or sorted `DataView` back to its source record. For
+    /// `DataView`s with multiple frames, use
+    /// [frame_row_ids](struct.DataView.html#method.frame_row_ids).
+    pub fn row_ids(&self) -> Vec<usize> {
+        self.frames.head.value_ref().row_ids()
+    }
+}
+
 impl<Labels, Frames> DataView<Labels, Frames>
 where
     Frames: UpdatePermutation,
@@ -750,96 +1212,397 @@ where
         self.frames.update_permutation(&perm);
         perm
     }
-}
 
-/// Trait for finding a cons-list of fields (implementing
-/// [DataIndex](../access/trait.DataIndex.html)) from frames list `Frames` using the `LabelList`
-/// list of labels. `LabelList` should consist of labels that exist within `Self` (this trait is
-/// implemented by label lookup lists).
-pub trait FieldList<LabelList, Frames> {
-    /// Resultant cons-list of fields.
-    type Output;
-
-    /// Returns the cons-list of fields from the frames list `frames`.
-    fn field_list(frames: &Frames) -> Self::Output;
-}
+    /// Filters this `DataView` by `predicate`, a function of the values of multiple fields of a
+    /// record. `LabelList` is a [LabelCons](../label/type.LabelCons.html) list of labels (which can
+    /// be generated using the [Labels](../label/macro.Labels.html) macro), and `predicate` is
+    /// called with one `Value<&T>` argument for each label in `LabelList` (in the same order),
+    /// where `T` is the type of the labeled field. For example,
+    /// `dv.filter_records::<Labels![Gdp, Region], _>(|gdp: Value<&f64>, region: Value<&String>|
+    /// gdp > valref![1e9] && region.exists())`. Up to eight fields are supported.
+    ///
+    /// Mutates this `DataView` so only those records matching `predicate` remain. Returns the
+    /// indices of the records that matched `predicate` in the original `DataView` (before
+    /// filtering).
+    pub fn filter_records<LabelList, P>(&mut self, predicate: P) -> Vec<usize>
+    where
+        Self: SelectFieldList<LabelList>,
+        <Self as SelectFieldList<LabelList>>::Output: FilterRecordsPerm<P>,
+    {
+        let perm =
+            SelectFieldList::<LabelList>::select_field_list(self).filter_records_perm(predicate);
+        self.frames.update_permutation(&perm);
+        perm
+    }
 
-impl<LabelList, Frames> FieldList<LabelList, Frames> for Nil {
-    type Output = Nil;
+    /// Filters this `DataView` by a boolean `mask` field (such as one produced by the element-wise
+    /// comparison methods of [ElemCmp](../ops/trait.ElemCmp.html)), which must have the same
+    /// number of rows as this `DataView`. Mutates this `DataView` so only those rows where `mask`
+    /// is `true` remain; rows where `mask` is `false` or missing are removed.
+    ///
+    /// Returns the indices of the rows that remain in the original `DataView` (before filtering),
+    /// or a `LengthMismatch` error (leaving this `DataView` unchanged) if the length of `mask`
+    /// does not match the number of rows in this `DataView`.
+    pub fn filter_mask<M>(&mut self, mask: &M) -> error::Result<Vec<usize>>
+    where
+        Frames: NRows,
+        M: DataIndex<DType = bool>,
+    {
+        let nrows = self.nrows();
+        if mask.len() != nrows {
+            return Err(error::AgnesError::LengthMismatch {
+                expected: nrows,
+                actual: mask.len(),
+            });
+        }
+        let perm = mask
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, value)| match value {
+                Value::Exists(&true) => Some(idx),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        self.frames.update_permutation(&perm);
+        Ok(perm)
+    }
 
-    fn field_list(_frames: &Frames) -> Nil {
-        Nil
+    /// Filters out the records of this `DataView` where any of the fields in `LabelList` are
+    /// missing. `LabelList` is a [LabelCons](../label/type.LabelCons.html) list of labels (which
+    /// can be generated using the [Labels](../label/macro.Labels.html) macro). For example,
+    /// `dv.drop_na::<Labels![Gdp, LifeExp]>()` keeps only the records where both GDP and life
+    /// expectancy exist.
+    ///
+    /// Returns the indices of the records that remain in the original `DataView` (before
+    /// filtering).
+    pub fn drop_na<LabelList>(&mut self) -> Vec<usize>
+    where
+        Frames: NRows,
+        Self: SelectFieldList<LabelList>,
+        <Self as SelectFieldList<LabelList>>::Output: RecordNaCount,
+    {
+        let fields = SelectFieldList::<LabelList>::select_field_list(self);
+        let perm = (0..self.nrows())
+            .filter(|&idx| fields.record_num_na(idx) == 0)
+            .collect::<Vec<_>>();
+        self.frames.update_permutation(&perm);
+        perm
     }
-}
 
-impl<LabelList, Frames, Label, FrameIndex, FrameLabel, Tail> FieldList<LabelList, Frames>
-    for FrameLookupCons<Label, FrameIndex, FrameLabel, Tail>
-where
-    LabelList: Member<Label>,
-    Self: FieldListPred<LabelList, Frames, <LabelList as Member<Label>>::IsMember>,
-{
-    type Output =
-        <Self as FieldListPred<LabelList, Frames, <LabelList as Member<Label>>::IsMember>>::Output;
+    /// Filters out the records of this `DataView` where all of the fields in `LabelList` are
+    /// missing. `LabelList` is a [LabelCons](../label/type.LabelCons.html) list of labels (which
+    /// can be generated using the [Labels](../label/macro.Labels.html) macro).
+    ///
+    /// Returns the indices of the records that remain in the original `DataView` (before
+    /// filtering).
+    pub fn drop_na_all<LabelList>(&mut self) -> Vec<usize>
+    where
+        Frames: NRows,
+        Self: SelectFieldList<LabelList>,
+        <Self as SelectFieldList<LabelList>>::Output: RecordNaCount,
+    {
+        let fields = SelectFieldList::<LabelList>::select_field_list(self);
+        let num_fields = fields.num_fields();
+        let perm = (0..self.nrows())
+            .filter(|&idx| num_fields == 0 || fields.record_num_na(idx) < num_fields)
+            .collect::<Vec<_>>();
+        self.frames.update_permutation(&perm);
+        perm
+    }
 
-    fn field_list(frames: &Frames) -> Self::Output {
-        Self::field_list_pred(frames)
+    /// Restricts this `DataView` to its first `n` records (or all records, if there are fewer
+    /// than `n`). Returns the indices of the records that remain in the original `DataView`.
+    pub fn head(&mut self, n: usize) -> Vec<usize>
+    where
+        Frames: NRows,
+    {
+        self.slice(..n)
     }
-}
 
-/// Helper trait for ([FieldList](trait.FieldList.html)). `IsMember` is whether or not the head of
-/// `Self` is a member of the list `LabelList`.
-pub trait FieldListPred<LabelList, Frames, IsMember> {
-    /// The output field list.
-    type Output;
+    /// Restricts this `DataView` to its last `n` records (or all records, if there are fewer
+    /// than `n`). Returns the indices of the records that remain in the original `DataView`.
+    pub fn tail(&mut self, n: usize) -> Vec<usize>
+    where
+        Frames: NRows,
+    {
+        let nrows = self.nrows();
+        self.slice(nrows.saturating_sub(n)..)
+    }
 
-    /// Returns the cons-list of fields from `frames`.
-    fn field_list_pred(frames: &Frames) -> Self::Output;
-}
+    /// Restricts this `DataView` to the records whose positions are within `range` (e.g.
+    /// `dv.slice(100..200)`). Any part of `range` beyond the end of this `DataView` is ignored.
+    /// Returns the indices of the records that remain in the original `DataView`.
+    pub fn slice<R>(&mut self, range: R) -> Vec<usize>
+    where
+        Frames: NRows,
+        R: RangeBounds<usize>,
+    {
+        let nrows = self.nrows();
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => nrows,
+        };
+        let end = end.min(nrows);
+        let perm = (start.min(end)..end).collect::<Vec<_>>();
+        self.frames.update_permutation(&perm);
+        perm
+    }
 
-impl<LabelList, Frames, Label, FrameIndex, FrameLabel, Tail> FieldListPred<LabelList, Frames, True>
-    for FrameLookupCons<Label, FrameIndex, FrameLabel, Tail>
-where
-    Frames: SelectFieldFromLabels<Self, Label>,
-    Tail: FieldList<LabelList, Frames>,
-{
-    type Output = Cons<
-        <Frames as SelectFieldFromLabels<
-            FrameLookupCons<Label, FrameIndex, FrameLabel, Tail>,
-            Label,
-        >>::Output,
-        <Tail as FieldList<LabelList, Frames>>::Output,
-    >;
+    /// Restricts this `DataView` to the records at positions `indices`, in the order specified.
+    /// Indices may be repeated, in which case the record is repeated in the resulting `DataView`.
+    ///
+    /// Returns the indices of the records that remain in the original `DataView` (i.e.
+    /// `indices`), or an `IndexError` (leaving this `DataView` unchanged) if any of `indices` are
+    /// out of bounds.
+    pub fn take(&mut self, indices: &[usize]) -> error::Result<Vec<usize>>
+    where
+        Frames: NRows,
+    {
+        let nrows = self.nrows();
+        if let Some(&index) = indices.iter().find(|&&index| index >= nrows) {
+            return Err(error::AgnesError::IndexError { index, len: nrows });
+        }
+        self.frames.update_permutation(indices);
+        Ok(indices.to_vec())
+    }
 
-    fn field_list_pred(frames: &Frames) -> Self::Output {
-        Cons {
-            head: SelectFieldFromLabels::<Self, Label>::select_field(frames),
-            tail: Tail::field_list(frames),
+    /// Restricts this `DataView` to a random sample of its records, selected without replacement
+    /// (each record appears at most once). `size` is either a number of records (`usize`) or a
+    /// fraction of the number of records in this `DataView` (`f64`); a sample larger than this
+    /// `DataView` contains all of its records. Samples are generated using a pseudo-random number
+    /// generator initialized with `seed`, so the same seed always results in the same sample.
+    ///
+    /// The sampled records are in random order. Returns the indices of the sampled records in the
+    /// original `DataView`, or an `InvalidOp` error (leaving this `DataView` unchanged) if `size`
+    /// is a negative or non-finite fraction.
+    pub fn sample<S>(&mut self, size: S, seed: u64) -> error::Result<Vec<usize>>
+    where
+        Frames: NRows,
+        S: Into<SampleSize>,
+    {
+        let nrows = self.nrows();
+        let n = size.into().num_records(nrows)?.min(nrows);
+        let mut rng = SplitMix64::new(seed);
+        let mut perm = (0..nrows).collect::<Vec<_>>();
+        // partial Fisher-Yates shuffle
+        for i in 0..n {
+            let j = i + rng.next_below(nrows - i);
+            perm.swap(i, j);
         }
+        perm.truncate(n);
+        self.frames.update_permutation(&perm);
+        Ok(perm)
     }
-}
 
-impl<LabelList, Frames, Label, FrameIndex, FrameLabel, Tail> FieldListPred<LabelList, Frames, False>
-    for FrameLookupCons<Label, FrameIndex, FrameLabel, Tail>
+    /// Restricts this `DataView` to a random sample of its records, selected with replacement
+    /// (records may appear multiple times). `size` is either a number of records (`usize`) or a
+    /// fraction of the number of records in this `DataView` (`f64`), and may be larger than this
+    /// `DataView`. Samples are generated using a pseudo-random number generator initialized with
+    /// `seed`, so the same seed always results in the same sample.
+    ///
+    /// Returns the indices of the sampled records in the original `DataView`, or an `InvalidOp`
+    /// error (leaving this `DataView` unchanged) if `size` is a negative or non-finite fraction,
+    /// or if the sample is too large to allocate.
+    pub fn sample_with_replacement<S>(&mut self, size: S, seed: u64) -> error::Result<Vec<usize>>
+    where
+        Frames: NRows,
+        S: Into<SampleSize>,
+    {
+        let nrows = self.nrows();
+        let n = size.into().num_records(nrows)?;
+        let n = if nrows == 0 { 0 } else { n };
+        let mut perm = Vec::new();
+        perm.try_reserve_exact(n).map_err(|_| {
+            error::AgnesError::InvalidOp(format!("unable to allocate sample of {} records", n))
+        })?;
+        let mut rng = SplitMix64::new(seed);
+        perm.extend((0..n).map(|_| rng.next_below(nrows)));
+        self.frames.update_permutation(&perm);
+        Ok(perm)
+    }
+
+    /// Sorts this `DataView` by multiple fields. `Keys` is a
+    /// [LabelCons](../label/type.LabelCons.html) list of sort keys (which can be generated using the
+    /// [Labels](../label/macro.Labels.html) macro), each of which is either
+    /// [Ascending](struct.Ascending.html) or [Descending](struct.Descending.html). Records are
+    /// ordered by the first key, with ties broken by the second key, and so on. This sort is
+    /// stable -- it preserves the original order of records which are equal in all keys. Returns
+    /// the permutation (list of indices in sorted order) of records.
+    ///
+    /// For example, `dv.sort_by_labels::<Labels![Ascending<Region>, Descending<Gdp, NaLast>]>()`
+    /// sorts by region in ascending order, then by GDP in descending order with missing GDP
+    /// values at the end.
+    pub fn sort_by_labels<Keys>(&mut self) -> Vec<usize>
+    where
+        Frames: NRows,
+        Keys: SortKeys<Self>,
+    {
+        let fields = Keys::select_sort_fields(self);
+        let mut sorted = (0..self.nrows()).collect::<Vec<_>>();
+        sorted.sort_by(|&left, &right| Keys::compare(&fields, left, right));
+        self.frames.update_permutation(&sorted);
+        sorted
+    }
+}
+
+/// Sort key marker denoting missing (NA) values should be placed before existing values.
+#[derive(Debug, Clone)]
+pub struct NaFirst;
+/// Sort key marker denoting missing (NA) values should be placed after existing values.
+#[derive(Debug, Clone)]
+pub struct NaLast;
+
+/// Trait for specifying the placement of missing (NA) values in a sort.
+pub trait NaPlacement {
+    /// Returns the ordering of a missing (NA) value relative to an existing value.
+    fn na_ordering() -> Ordering;
+}
+impl NaPlacement for NaFirst {
+    fn na_ordering() -> Ordering {
+        Ordering::Less
+    }
+}
+impl NaPlacement for NaLast {
+    fn na_ordering() -> Ordering {
+        Ordering::Greater
+    }
+}
+
+/// Sort key for [sort_by_labels](struct.DataView.html#method.sort_by_labels) specifying that the
+/// field labeled `Label` should be sorted in ascending order. `Na` ([NaFirst](struct.NaFirst.html)
+/// or [NaLast](struct.NaLast.html)) specifies the placement of missing values.
+#[derive(Debug, Clone)]
+pub struct Ascending<Label, Na = NaFirst> {
+    _marker: PhantomData<(Label, Na)>,
+}
+/// Sort key for [sort_by_labels](struct.DataView.html#method.sort_by_labels) specifying that the
+/// field labeled `Label` should be sorted in descending order. `Na` ([NaFirst](struct.NaFirst.html)
+/// or [NaLast](struct.NaLast.html)) specifies the placement of missing values.
+#[derive(Debug, Clone)]
+pub struct Descending<Label, Na = NaFirst> {
+    _marker: PhantomData<(Label, Na)>,
+}
+
+/// Compares two existing values which implement `PartialOrd`. Incomparable values (such as `NaN`)
+/// are considered to be less than comparable values.
+fn compare_values<T: PartialOrd>(left: &T, right: &T) -> Ordering {
+    left.partial_cmp(right).unwrap_or_else(|| {
+        match (
+            left.partial_cmp(left).is_some(),
+            right.partial_cmp(right).is_some(),
+        ) {
+            (false, true) => Ordering::Less,
+            (true, false) => Ordering::Greater,
+            _ => Ordering::Equal,
+        }
+    })
+}
+
+/// Trait for a single sort key used to sort a `View` (a [DataView](struct.DataView.html)).
+pub trait SortKey<View> {
+    /// The field (implementing [DataIndex](../access/trait.DataIndex.html)) being sorted on.
+    type Field;
+
+    /// Selects the field being sorted on from `view`.
+    fn select_sort_field(view: &View) -> Self::Field;
+    /// Compares the records at indices `left` and `right` of `field`.
+    fn compare(field: &Self::Field, left: usize, right: usize) -> Ordering;
+}
+
+macro_rules! impl_sort_key {
+    ($($key:ident: $reverse:expr;)*) => {$(
+        impl<View, Label, Na> SortKey<View> for $key<Label, Na>
+        where
+            View: SelectFieldByLabel<Label>,
+            VFieldTypeOf<View, Label>: PartialOrd,
+            Na: NaPlacement,
+        {
+            type Field = VFieldOf<View, Label>;
+
+            fn select_sort_field(view: &View) -> Self::Field {
+                view.select_field()
+            }
+            fn compare(field: &Self::Field, left: usize, right: usize) -> Ordering {
+                // indices are always in range, so unwraps are safe
+                match (field.get_datum(left).unwrap(), field.get_datum(right).unwrap()) {
+                    (Value::Na, Value::Na) => Ordering::Equal,
+                    (Value::Na, Value::Exists(_)) => Na::na_ordering(),
+                    (Value::Exists(_), Value::Na) => Na::na_ordering().reverse(),
+                    (Value::Exists(left), Value::Exists(right)) => {
+                        let ordering = compare_values(left, right);
+                        if $reverse {
+                            ordering.reverse()
+                        } else {
+                            ordering
+                        }
+                    }
+                }
+            }
+        }
+    )*}
+}
+impl_sort_key![
+    Ascending: false;
+    Descending: true;
+];
+
+/// Trait for a [LabelCons](../label/type.LabelCons.html) list of sort keys used to sort a `View`
+/// (a [DataView](struct.DataView.html)).
+pub trait SortKeys<View> {
+    /// Cons-list of fields being sorted on.
+    type Fields;
+
+    /// Selects the fields being sorted on from `view`.
+    fn select_sort_fields(view: &View) -> Self::Fields;
+    /// Compares the records at indices `left` and `right` of `fields`, comparing by each key in
+    /// turn until the records are found to be unequal.
+    fn compare(fields: &Self::Fields, left: usize, right: usize) -> Ordering;
+}
+impl<View> SortKeys<View> for Nil {
+    type Fields = Nil;
+
+    fn select_sort_fields(_view: &View) -> Nil {
+        Nil
+    }
+    fn compare(_fields: &Nil, _left: usize, _right: usize) -> Ordering {
+        Ordering::Equal
+    }
+}
+impl<View, Key, Tail> SortKeys<View> for LCons<Key, Tail>
 where
-    Tail: FieldList<LabelList, Frames>,
+    Key: SortKey<View>,
+    Tail: SortKeys<View>,
 {
-    type Output = <Tail as FieldList<LabelList, Frames>>::Output;
+    type Fields = Cons<Key::Field, Tail::Fields>;
 
-    fn field_list_pred(frames: &Frames) -> Self::Output {
-        Tail::field_list(frames)
+    fn select_sort_fields(view: &View) -> Self::Fields {
+        Cons {
+            head: Key::select_sort_field(view),
+            tail: Tail::select_sort_fields(view),
+        }
+    }
+    fn compare(fields: &Self::Fields, left: usize, right: usize) -> Ordering {
+        Key::compare(&fields.head, left, right)
+            .then_with(|| Tail::compare(&fields.tail, left, right))
     }
 }
 
 /// A struct representing a single record across the fields in the field list `Fields`.
 #[derive(Debug, Clone)]
 pub struct Record<'a, Fields> {
-    // a field cons-list (returned from FieldList trait method)
+    // a field cons-list (returned from SelectFieldList trait method)
     fields: &'a Fields,
     idx: usize,
 }
 
 impl<'a, Fields> Record<'a, Fields> {
-    fn new(field_list: &'a Fields, idx: usize) -> Record<'a, Fields> {
+    pub(crate) fn new(field_list: &'a Fields, idx: usize) -> Record<'a, Fields> {
         Record {
             fields: field_list,
             idx,
@@ -905,8 +1668,9 @@ where
 
 /// Trait for computing equality of a single index (record) within a list of data fields.
 pub trait PartialEqIndex {
-    /// Returns equality of the values within this list of data fields with the index `idx`.
-    fn eq_index(&self, other: &Self, idx: usize) -> bool;
+    /// Returns equality of the values within this list of data fields with the index `idx` and
+    /// the values within the `other` list of data fields with the index `other_idx`.
+    fn eq_index(&self, other: &Self, idx: usize, other_idx: usize) -> bool;
 }
 
 impl<T> PartialEqIndex for Framed<T>
@@ -914,15 +1678,15 @@ where
     for<'a> Value<&'a T>: PartialEq,
     Self: DataIndex<DType = T>,
 {
-    fn eq_index(&self, other: &Self, idx: usize) -> bool {
+    fn eq_index(&self, other: &Self, idx: usize, other_idx: usize) -> bool {
         self.get_datum(idx)
             .unwrap()
-            .eq(&other.get_datum(idx).unwrap())
+            .eq(&other.get_datum(other_idx).unwrap())
     }
 }
 
 impl PartialEqIndex for Nil {
-    fn eq_index(&self, _other: &Nil, _idx: usize) -> bool {
+    fn eq_index(&self, _other: &Nil, _idx: usize, _other_idx: usize) -> bool {
         true
     }
 }
@@ -932,8 +1696,9 @@ where
     Head: PartialEqIndex,
     Tail: PartialEqIndex,
 {
-    fn eq_index(&self, other: &Self, idx: usize) -> bool {
-        self.head.eq_index(&other.head, idx) && self.tail.eq_index(&other.tail, idx)
+    fn eq_index(&self, other: &Self, idx: usize, other_idx: usize) -> bool {
+        self.head.eq_index(&other.head, idx, other_idx)
+            && self.tail.eq_index(&other.tail, idx, other_idx)
     }
 }
 
@@ -942,7 +1707,7 @@ where
     Fields: PartialEqIndex,
 {
     fn eq(&self, other: &Self) -> bool {
-        self.fields.eq_index(other.fields, self.idx)
+        self.fields.eq_index(other.fields, self.idx, other.idx)
     }
 }
 
@@ -970,14 +1735,88 @@ where
     }
 }
 
+/// The size of a random sample of records from a `DataView`, either as a number of records or as a
+/// fraction of the number of records in the `DataView`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SampleSize {
+    /// Sample a specific number of records.
+    Count(usize),
+    /// Sample a fraction of the records (e.g. `0.1` for 10% of the records). The fraction must be
+    /// finite and non-negative.
+    Fraction(f64),
+}
+
+impl SampleSize {
+    fn num_records(self, nrows: usize) -> error::Result<usize> {
+        match self {
+            SampleSize::Count(count) => Ok(count),
+            SampleSize::Fraction(fraction) => {
+                if !fraction.is_finite() || fraction < 0.0 {
+                    return Err(error::AgnesError::InvalidOp(format!(
+                        "invalid sample fraction: {}",
+                        fraction
+                    )));
+                }
+                // saturates at `usize::MAX` for very large fractions
+                Ok((fraction * nrows as f64).round() as usize)
+            }
+        }
+    }
+}
+
+impl From<usize> for SampleSize {
+    fn from(count: usize) -> SampleSize {
+        SampleSize::Count(count)
+    }
+}
+
+impl From<f64> for SampleSize {
+    fn from(fraction: f64) -> SampleSize {
+        SampleSize::Fraction(fraction)
+    }
+}
+
+/// Small, fast pseudo-random number generator (SplitMix64), used for reproducible sampling.
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> SplitMix64 {
+        SplitMix64 { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a pseudo-random number in the range `0..bound`. `bound` must be non-zero.
+    fn next_below(&mut self, bound: usize) -> usize {
+        ((u128::from(self.next_u64()) * bound as u128) >> 64) as usize
+    }
+}
+
+/// Specifies which record to keep among a set of duplicate records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Keep {
+    /// Keep the first of the duplicate records.
+    First,
+    /// Keep the last of the duplicate records.
+    Last,
+}
+
 impl<Labels, Frames> DataView<Labels, Frames> {
     /// Returns a cons-list of fields (implementing [DataIndex](../access/trait.DataIndex.html))
-    /// that match the labels in `LabelList`.
-    pub fn field_list<LabelList>(&self) -> <Labels as FieldList<LabelList, Frames>>::Output
+    /// that match the labels in `LabelList`, in the same order as `LabelList`.
+    pub fn field_list<LabelList>(&self) -> <Self as SelectFieldList<LabelList>>::Output
     where
-        Labels: FieldList<LabelList, Frames>,
+        Self: SelectFieldList<LabelList>,
     {
-        <Labels as FieldList<LabelList, Frames>>::field_list(&self.frames)
+        SelectFieldList::<LabelList>::select_field_list(self)
     }
 
     /// Computes the set of unique composite values among the fields in this `DataView` associated
@@ -989,23 +1828,75 @@ impl<Labels, Frames> DataView<Labels, Frames> {
     /// Fields referenced by `LabelList` must implement `Hash`.
     pub fn unique_indices<LabelList>(&self) -> Vec<usize>
     where
-        Labels: FieldList<LabelList, Frames>,
-        <Labels as FieldList<LabelList, Frames>>::Output: HashIndex + PartialEqIndex,
+        Self: SelectFieldList<LabelList>,
+        <Self as SelectFieldList<LabelList>>::Output: HashIndex + PartialEqIndex,
+        Frames: NRows,
+    {
+        self.unique_indices_keep::<LabelList>(Keep::First)
+    }
+
+    fn unique_indices_keep<LabelList>(&self, keep: Keep) -> Vec<usize>
+    where
+        Self: SelectFieldList<LabelList>,
+        <Self as SelectFieldList<LabelList>>::Output: HashIndex + PartialEqIndex,
         Frames: NRows,
     {
         let fl = self.field_list::<LabelList>();
-        let mut indices = vec![];
         let mut set = HashSet::new();
-        for i in 0..self.nrows() {
-            let record = Record::new(&fl, i);
-            if !set.contains(&record) {
-                set.insert(record);
-                indices.push(i);
+        match keep {
+            Keep::First => (0..self.nrows())
+                .filter(|&i| set.insert(Record::new(&fl, i)))
+                .collect(),
+            Keep::Last => {
+                let mut indices = (0..self.nrows())
+                    .rev()
+                    .filter(|&i| set.insert(Record::new(&fl, i)))
+                    .collect::<Vec<_>>();
+                indices.reverse();
+                indices
             }
         }
+    }
+
+    /// Filters out the records of this `DataView` whose values of the fields labeled by
+    /// `LabelList` duplicate those of another record, keeping either the first or the last of each
+    /// set of duplicate records (as specified by `keep`). Unlike
+    /// [unique_values](struct.DataView.html#method.unique_values), all fields of this `DataView`
+    /// are retained.
+    ///
+    /// Returns the indices of the records that remain in the original `DataView` (before
+    /// filtering). Fields referenced by `LabelList` must implement `Hash`.
+    pub fn distinct<LabelList>(&mut self, keep: Keep) -> Vec<usize>
+    where
+        Self: SelectFieldList<LabelList>,
+        <Self as SelectFieldList<LabelList>>::Output: HashIndex + PartialEqIndex,
+        Frames: NRows + UpdatePermutation,
+    {
+        let indices = self.unique_indices_keep::<LabelList>(keep);
+        self.frames.update_permutation(&indices);
         indices
     }
 
+    /// Returns a boolean field which is `true` for each record of this `DataView` whose values of
+    /// the fields labeled by `LabelList` duplicate those of another record. Within each set of
+    /// duplicate records, the first or the last record (as specified by `keep`) is not considered
+    /// a duplicate. Filtering by the negation of this field is equivalent to
+    /// [distinct](struct.DataView.html#method.distinct).
+    ///
+    /// Fields referenced by `LabelList` must implement `Hash`.
+    pub fn duplicated<LabelList>(&self, keep: Keep) -> FieldData<bool>
+    where
+        Self: SelectFieldList<LabelList>,
+        <Self as SelectFieldList<LabelList>>::Output: HashIndex + PartialEqIndex,
+        Frames: NRows,
+    {
+        let mut duplicated = vec![true; self.nrows()];
+        for idx in self.unique_indices_keep::<LabelList>(keep) {
+            duplicated[idx] = false;
+        }
+        FieldData::from_vec(duplicated)
+    }
+
     /// Computes the set of unique composite values among the fields in this `DataView` associated
     /// with labels in `LabelList`. Returns a new `DataView` with those specific sets of values. The
     /// returned `DataView` contains the values of the `LabelList`-labeled fields that represent
@@ -1023,8 +1914,8 @@ impl<Labels, Frames> DataView<Labels, Frames> {
         Labels: HasLabels<LabelList> + LabelSubset<LabelList> + FrameIndexList,
         Frames: SubsetClone<<Labels as FrameIndexList>::LabelList>,
         <Frames as SubsetClone<<Labels as FrameIndexList>::LabelList>>::Output: UpdatePermutation,
-        Labels: FieldList<LabelList, Frames>,
-        <Labels as FieldList<LabelList, Frames>>::Output: HashIndex + PartialEqIndex,
+        Self: SelectFieldList<LabelList>,
+        <Self as SelectFieldList<LabelList>>::Output: HashIndex + PartialEqIndex,
         Frames: NRows,
     {
         let indices = self.unique_indices::<LabelList>();
@@ -1085,7 +1976,7 @@ where
         M: SerializeMap,
     {
         map.serialize_entry(
-            Label::name(),
+            &Label::name(),
             &SelectFieldFromLabels::<Self, Label>::select_field(frames),
         )?;
         Tail::serialize_view_field(frames, map)
@@ -1451,6 +2342,74 @@ mod tests {
         assert_eq!(dv3.field::<EmpId>().to_vec(), vec![8u64, 9, 6, 10, 0, 2, 5]);
     }
 
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn sort_multiple() {
+        use test_utils::emp_table::*;
+        use test_utils::extra_emp::*;
+        let orig_dv = sample_merged_emp_table();
+
+        // sort by department, then by descending vacation hours
+        let mut dv1 = orig_dv.clone();
+        let sorted = dv1.sort_by_labels::<Labels![Ascending<DeptId>, Descending<VacationHrs>]>();
+        assert_eq!(sorted, vec![2, 0, 3, 1, 4, 6, 5]);
+        assert_eq!(
+            dv1.field::<EmpName>().to_vec(),
+            vec!["Bob", "Sally", "Cara", "Jamie", "Louis", "Ann", "Louise"]
+        );
+
+        // sort by descending training status, then by name
+        let mut dv2 = dv1.clone();
+        dv2.sort_by_labels::<Labels![Descending<DidTraining>, Ascending<EmpName>]>();
+        assert_eq!(
+            dv2.field::<EmpName>().to_vec(),
+            vec!["Ann", "Bob", "Cara", "Louis", "Jamie", "Louise", "Sally"]
+        );
+
+        // make sure dv1 is unchanged
+        assert_eq!(
+            dv1.field::<EmpName>().to_vec(),
+            vec!["Bob", "Sally", "Cara", "Jamie", "Louis", "Ann", "Louise"]
+        );
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn sort_multiple_na() {
+        use field::FieldData;
+        use test_utils::emp_table::*;
+        let ds: emp_table::Store = emp_table_from_field![
+            FieldData::from(vec![0u64, 1, 2, 3, 4]),
+            FieldData::from_field_vec(vec![
+                Value::Exists(2u64),
+                Value::Na,
+                Value::Exists(1),
+                Value::Na,
+                Value::Exists(2),
+            ]),
+            FieldData::from(
+                ["Sally", "Jamie", "Bob", "Cara", "Louis"]
+                    .iter()
+                    .map(|s| s.to_string())
+                    .collect::<Vec<_>>()
+            )
+        ];
+        let orig_dv = ds.into_view();
+
+        let mut dv = orig_dv.clone();
+        let sorted = dv.sort_by_labels::<Labels![Ascending<DeptId, NaLast>, Descending<EmpId>]>();
+        assert_eq!(sorted, vec![2, 4, 0, 3, 1]);
+        assert_eq!(dv.field::<EmpId>().to_vec(), vec![2u64, 4, 0, 3, 1]);
+
+        // NA placement is independent of sort direction
+        let mut dv = orig_dv.clone();
+        let sorted = dv.sort_by_labels::<Labels![Descending<DeptId>, Ascending<EmpId>]>();
+        assert_eq!(sorted, vec![1, 3, 0, 4, 2]);
+        let mut dv = orig_dv.clone();
+        let sorted = dv.sort_by_labels::<Labels![Descending<DeptId, NaLast>, Ascending<EmpId>]>();
+        assert_eq!(sorted, vec![0, 4, 2, 1, 3]);
+    }
+
     #[cfg(feature = "test-utils")]
     #[test]
     fn filter() {
@@ -1488,6 +2447,594 @@ mod tests {
         assert_eq!(dv2.field::<EmpName>().to_vec(), vec!["Louise", "Ann"]);
     }
 
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn filter_records() {
+        use test_utils::emp_table::*;
+        use test_utils::extra_emp::*;
+        let orig_dv = sample_merged_emp_table();
+
+        // OR condition across two fields
+        let mut dv1 = orig_dv.clone();
+        let perm = dv1.filter_records::<Labels![DeptId, VacationHrs], _>(
+            |dept_id: Value<&u64>, hrs: Value<&f32>| dept_id == valref![1] || hrs > valref![50.0],
+        );
+        assert_eq!(perm, vec![0, 1, 2, 3]);
+        assert_eq!(
+            dv1.field::<EmpName>().to_vec(),
+            vec!["Sally", "Jamie", "Bob", "Cara"]
+        );
+
+        // arguments are provided in label list order, not field order
+        let perm = dv1.filter_records::<Labels![DidTraining, EmpName], _>(
+            |trained: Value<&bool>, name: Value<&String>| {
+                trained == valref![true] && name != valref!["Cara".to_string()]
+            },
+        );
+        assert_eq!(perm, vec![2]);
+        assert_eq!(dv1.field::<EmpName>().to_vec(), vec!["Bob"]);
+        assert_eq!(dv1.field::<EmpId>().to_vec(), vec![5]);
+
+        // three fields
+        let mut dv2 = orig_dv.clone();
+        dv2.filter_records::<Labels![SalaryOffset, EmpId, DidTraining], _>(
+            |offset: Value<&i64>, emp_id: Value<&u64>, trained: Value<&bool>| {
+                offset >= valref![0] && emp_id > valref![2] && trained == valref![true]
+            },
+        );
+        assert_eq!(dv2.field::<EmpName>().to_vec(), vec!["Bob", "Louis"]);
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn filter_mask() {
+        use ops::ElemCmp;
+        use test_utils::emp_table::*;
+        use test_utils::extra_emp::*;
+        let orig_dv = sample_merged_emp_table();
+
+        // mask from a scalar comparison
+        let mut dv1 = orig_dv.clone();
+        let mask = dv1.field::<VacationHrs>().elem_gt(50.0);
+        let perm = dv1.filter_mask(&mask).unwrap();
+        assert_eq!(perm.len(), dv1.nrows());
+        assert_eq!(
+            dv1.field::<VacationHrs>()
+                .iter()
+                .all(|hrs| hrs > valref![50.0]),
+            true
+        );
+
+        // masks are relative to the current (filtered) order
+        let mask = dv1.field::<DeptId>().elem_eq(1);
+        let expected = dv1
+            .field::<DeptId>()
+            .iter()
+            .enumerate()
+            .filter(|&(_, dept_id)| dept_id == valref![1])
+            .map(|(idx, _)| idx)
+            .collect::<Vec<_>>();
+        assert_eq!(dv1.filter_mask(&mask).unwrap(), expected);
+        assert_eq!(
+            dv1.field::<DeptId>().iter().all(|id| id == valref![1]),
+            true
+        );
+
+        // missing mask values are filtered out
+        let mut dv2 = orig_dv.clone();
+        let mask = FieldData::from_field_vec(vec![
+            Value::Exists(true),
+            Value::Na,
+            Value::Exists(false),
+            Value::Exists(true),
+            Value::Na,
+            Value::Na,
+            Value::Exists(true),
+        ]);
+        assert_eq!(dv2.filter_mask(&mask).unwrap(), vec![0, 3, 6]);
+        assert_eq!(dv2.nrows(), 3);
+
+        // mismatched lengths leave the view unchanged
+        let mut dv3 = orig_dv.clone();
+        match dv3.filter_mask(&FieldData::from_vec(vec![true, false])) {
+            Err(AgnesError::LengthMismatch { expected, actual }) => {
+                assert_eq!((expected, actual), (7, 2));
+            }
+            _ => panic!("expected LengthMismatch error"),
+        }
+        assert_eq!(dv3.nrows(), 7);
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn slicing() {
+        use test_utils::emp_table::*;
+        let orig_dv = sample_emp_table().into_view();
+        let names = |dv: &emp_table::View| dv.field::<EmpName>().to_vec();
+
+        let mut dv = orig_dv.clone();
+        assert_eq!(dv.head(2), vec![0, 1]);
+        assert_eq!(names(&dv), vec!["Sally", "Jamie"]);
+        let mut dv = orig_dv.clone();
+        assert_eq!(dv.head(10), vec![0, 1, 2, 3, 4, 5, 6]);
+
+        let mut dv = orig_dv.clone();
+        assert_eq!(dv.tail(3), vec![4, 5, 6]);
+        assert_eq!(names(&dv), vec!["Louis", "Louise", "Ann"]);
+        // tail of a view which has already been sliced
+        assert_eq!(dv.tail(1), vec![2]);
+        assert_eq!(names(&dv), vec!["Ann"]);
+        let mut dv = orig_dv.clone();
+        assert_eq!(dv.tail(10).len(), 7);
+
+        let mut dv = orig_dv.clone();
+        assert_eq!(dv.slice(2..4), vec![2, 3]);
+        assert_eq!(names(&dv), vec!["Bob", "Cara"]);
+        let mut dv = orig_dv.clone();
+        assert_eq!(dv.slice(5..=6), vec![5, 6]);
+        let mut dv = orig_dv.clone();
+        assert_eq!(dv.slice(5..100), vec![5, 6]);
+        let mut dv = orig_dv.clone();
+        assert_eq!(dv.slice(8..), Vec::<usize>::new());
+        assert_eq!(dv.nrows(), 0);
+
+        // slicing is relative to the current order of the view
+        let mut dv = orig_dv.clone();
+        dv.sort_by_label::<EmpName>();
+        dv.head(3);
+        assert_eq!(names(&dv), vec!["Ann", "Bob", "Cara"]);
+
+        let mut dv = orig_dv.clone();
+        assert_eq!(dv.take(&[6, 0, 6]).unwrap(), vec![6, 0, 6]);
+        assert_eq!(names(&dv), vec!["Ann", "Sally", "Ann"]);
+        let mut dv = orig_dv.clone();
+        match dv.take(&[1, 7]) {
+            Err(AgnesError::IndexError { index, len }) => assert_eq!((index, len), (7, 7)),
+            _ => panic!("expected IndexError"),
+        }
+        assert_eq!(dv.nrows(), 7);
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn sample() {
+        use test_utils::emp_table::*;
+        let orig_dv = sample_emp_table().into_view();
+
+        let mut dv = orig_dv.clone();
+        let perm = dv.sample(4, 1234).unwrap();
+        assert_eq!(dv.nrows(), 4);
+        let mut sorted = perm.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted.len(), 4);
+        assert!(sorted.iter().all(|&idx| idx < 7));
+        let emp_ids = orig_dv.field::<EmpId>();
+        assert_eq!(
+            dv.field::<EmpId>().to_vec(),
+            perm.iter()
+                .map(|&idx| *emp_ids.get_datum(idx).unwrap().unwrap())
+                .collect::<Vec<_>>()
+        );
+
+        // same seed results in the same sample
+        let mut dv2 = orig_dv.clone();
+        assert_eq!(dv2.sample(4, 1234).unwrap(), perm);
+        // fraction of the records
+        let mut dv2 = orig_dv.clone();
+        assert_eq!(dv2.sample(0.5, 1234).unwrap().len(), 4);
+        let mut dv2 = orig_dv.clone();
+        assert_eq!(
+            dv2.sample(SampleSize::Fraction(0.0), 1234).unwrap().len(),
+            0
+        );
+        // without replacement, sample contains at most all the records
+        let mut dv2 = orig_dv.clone();
+        let mut all = dv2.sample(100, 99).unwrap();
+        all.sort();
+        assert_eq!(all, vec![0, 1, 2, 3, 4, 5, 6]);
+
+        let mut dv = orig_dv.clone();
+        let perm = dv.sample_with_replacement(20, 42).unwrap();
+        assert_eq!(dv.nrows(), 20);
+        assert!(perm.iter().all(|&idx| idx < 7));
+        let mut dv2 = orig_dv.clone();
+        assert_eq!(dv2.sample_with_replacement(20, 42).unwrap(), perm);
+        let mut dv2 = orig_dv.clone();
+        assert_eq!(dv2.sample_with_replacement(2.0, 42).unwrap().len(), 14);
+
+        // negative and non-finite fractions are rejected
+        let mut dv = orig_dv.clone();
+        for &fraction in &[-0.5, ::std::f64::INFINITY, ::std::f64::NAN] {
+            match dv.sample(fraction, 42) {
+                Err(AgnesError::InvalidOp(_)) => {}
+                _ => panic!("expected InvalidOp error"),
+            }
+            match dv.sample_with_replacement(fraction, 42) {
+                Err(AgnesError::InvalidOp(_)) => {}
+                _ => panic!("expected InvalidOp error"),
+            }
+        }
+        assert_eq!(dv.nrows(), 7);
+        // samples with replacement which are too large to allocate are rejected
+        match dv.sample_with_replacement(1e300, 42) {
+            Err(AgnesError::InvalidOp(_)) => {}
+            _ => panic!("expected InvalidOp error"),
+        }
+        match dv.sample_with_replacement(::std::usize::MAX, 42) {
+            Err(AgnesError::InvalidOp(_)) => {}
+            _ => panic!("expected InvalidOp error"),
+        }
+        assert_eq!(dv.sample(1e300, 42).unwrap().len(), 7);
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn drop_na() {
+        use ops::FillNa;
+        use test_utils::emp_table::*;
+
+        let ds: emp_table::Store = emp_table_from_field![
+            FieldData::from_field_vec(vec![
+                Value::Exists(5u64),
+                Value::Na,
+                Value::Exists(3),
+                Value::Na,
+            ]),
+            FieldData::from_field_vec(vec![
+                Value::Exists(2u64),
+                Value::Exists(4),
+                Value::Na,
+                Value::Na,
+            ]),
+            FieldData::from(vec![
+                "a".to_string(),
+                "b".to_string(),
+                "c".to_string(),
+                "d".to_string(),
+            ])
+        ];
+        let orig_dv = ds.into_view();
+
+        let mut dv = orig_dv.clone();
+        assert_eq!(dv.drop_na::<Labels![EmpId, DeptId]>(), vec![0]);
+        assert_eq!(dv.field::<EmpName>().to_vec(), vec!["a"]);
+
+        let mut dv = orig_dv.clone();
+        assert_eq!(dv.drop_na_all::<Labels![EmpId, DeptId]>(), vec![0, 1, 2]);
+        assert_eq!(dv.field::<EmpName>().to_vec(), vec!["a", "b", "c"]);
+
+        let mut dv = orig_dv.clone();
+        assert_eq!(dv.drop_na::<Labels![DeptId]>(), vec![0, 1]);
+        assert_eq!(dv.drop_na::<Labels![EmpName]>(), vec![0, 1]);
+
+        // filling operates in the current order of the view
+        let mut dv = orig_dv.clone();
+        dv.sort_by_label_comparator::<EmpName, _>(|left: Value<&String>, right: Value<&String>| {
+            right.cmp(&left)
+        });
+        assert_eq!(
+            dv.field::<DeptId>().ffill().to_value_vec(),
+            vec![Value::Na, Value::Na, Value::Exists(4), Value::Exists(2)]
+        );
+        assert_eq!(dv.field::<DeptId>().bfill().to_vec(), vec![4, 4, 4, 2]);
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn materialize() {
+        use test_utils::emp_table::*;
+        use test_utils::extra_emp::*;
+        let mut dv = sample_merged_emp_table();
+        assert_eq!(dv.nframes(), 2);
+        dv.filter::<DidTraining, _>(|val: Value<&bool>| val == valref![true]);
+        dv.sort_by_label::<EmpName>();
+        let dv = dv.relabel::<EmpId, emp_table3::EmployeeId>();
+
+        let mat_dv = dv.materialize().unwrap();
+        assert_eq!(mat_dv.nframes(), 1);
+        assert_eq!(mat_dv.nrows(), 4);
+        assert_eq!(mat_dv.fieldnames(), dv.fieldnames());
+        assert_eq!(
+            mat_dv.field::<EmpName>().to_vec(),
+            vec!["Ann", "Bob", "Cara", "Louis"]
+        );
+        assert_eq!(
+            mat_dv.field::<emp_table3::EmployeeId>().to_vec(),
+            vec![10u64, 5, 6, 8]
+        );
+        assert_eq!(mat_dv.field::<DeptId>().to_vec(), vec![4u64, 1, 1, 3]);
+        assert_eq!(
+            mat_dv.field::<SalaryOffset>().to_vec(),
+            vec![-1i64, 12, -33, 10]
+        );
+        // the materialized view references only its own store
+        assert_eq!(mat_dv.store_ref_counts(), vec![1]);
+        assert_eq!(dv.store_ref_counts(), vec![1, 1]);
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn send_sync() {
+        use std::sync::Arc;
+        use std::thread;
+        use test_utils::emp_table::*;
+
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<emp_table::Store>();
+        assert_send_sync::<emp_table::View>();
+        assert_send_sync::<Framed<String>>();
+        assert_send_sync::<<emp_table::View as ViewMerge<extra_emp::View>>::Output>();
+
+        let mut dv = sample_merged_emp_table();
+        dv.sort_by_label::<EmpName>();
+        let dv = Arc::new(dv);
+        let handles = (0..4)
+            .map(|i| {
+                let dv = Arc::clone(&dv);
+                thread::spawn(move || {
+                    let mut dv = (*dv).clone();
+                    dv.filter::<DeptId, _>(|val: Value<&u64>| val == i + 1);
+                    dv.field::<EmpName>().to_vec()
+                })
+            })
+            .collect::<Vec<_>>();
+        let results = handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                vec!["Bob", "Cara", "Sally"],
+                vec!["Jamie"],
+                vec!["Louis"],
+                vec!["Ann", "Louise"],
+            ]
+        );
+        // original view is unaffected by the filtering in each thread
+        assert_eq!(dv.nrows(), 7);
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn with_field_without() {
+        use test_utils::emp_table::*;
+        use test_utils::extra_emp::*;
+        let mut dv = sample_emp_table().into_view();
+        dv.filter::<DeptId, _>(|val: Value<&u64>| val == 1);
+        dv.sort_by_label::<EmpName>();
+        assert_eq!(dv.field::<EmpName>().to_vec(), vec!["Bob", "Cara", "Sally"]);
+
+        // new field values line up with the filtered and sorted records
+        let dv2 = dv
+            .with_field::<DidTraining, _>(FieldData::from_field_vec(vec![
+                Value::Exists(true),
+                Value::Na,
+                Value::Exists(false),
+            ]))
+            .unwrap();
+        assert_eq!(dv2.nframes(), 2);
+        assert_eq!(dv2.nfields(), 4);
+        assert_eq!(
+            dv2.fieldnames(),
+            vec!["EmpId", "DeptId", "EmpName", "DidTraining"]
+        );
+        assert_eq!(
+            dv2.field::<DidTraining>().iter().collect::<Vec<_>>(),
+            vec![valref![true], Value::Na, valref![false]]
+        );
+        assert_eq!(dv2.field::<EmpId>().to_vec(), vec![5u64, 6, 0]);
+
+        // length mismatch
+        match dv.with_field::<DidTraining, _>(FieldData::from(vec![true, false])) {
+            Err(AgnesError::DimensionMismatch(_)) => {}
+            _ => panic!("expected dimension mismatch"),
+        }
+
+        let dv3 = dv2.without::<DeptId>();
+        assert_eq!(dv3.nfields(), 3);
+        assert_eq!(dv3.fieldnames(), vec!["EmpId", "EmpName", "DidTraining"]);
+        assert_eq!(
+            dv3.field::<EmpName>().to_vec(),
+            vec!["Bob", "Cara", "Sally"]
+        );
+
+        // replace a field by removing it and adding it back
+        let dv4 = dv3
+            .without::<EmpName>()
+            .with_field::<EmpName, _>(FieldData::from(vec![
+                "Robert".to_string(),
+                "Cara".to_string(),
+                "Sal".to_string(),
+            ]))
+            .unwrap();
+        assert_eq!(dv4.fieldnames(), vec!["EmpId", "DidTraining", "EmpName"]);
+        assert_eq!(
+            dv4.field::<EmpName>().to_vec(),
+            vec!["Robert", "Cara", "Sal"]
+        );
+        // original view is unchanged
+        assert_eq!(dv.nfields(), 3);
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn compute() {
+        use test_utils::emp_table::*;
+        use test_utils::extra_emp::*;
+        namespace![
+            @continue(typenum::Add1<::test_utils::dept_table::Table>)
+            pub table computed {
+                HrsPerId: f32,
+                Summary: String,
+            }
+        ];
+        let mut dv = sample_merged_emp_table();
+        dv.sort_by_label::<EmpName>();
+        let dv = dv
+            .with_field::<computed::Summary, _>(FieldData::from_field_vec(vec![
+                Value::Exists("a".to_string()),
+                Value::Exists("b".to_string()),
+                Value::Na,
+                Value::Exists("d".to_string()),
+                Value::Exists("e".to_string()),
+                Value::Exists("f".to_string()),
+                Value::Exists("g".to_string()),
+            ]))
+            .unwrap();
+
+        // fields from different frames, with sorted permutation
+        let dv1 = dv
+            .compute::<Labels![VacationHrs, EmpId], computed::HrsPerId, _>(|hrs: &f32, id: &u64| {
+                hrs / (*id as f32 + 1.0)
+            })
+            .unwrap();
+        assert_eq!(dv1.nfields(), 8);
+        assert_eq!(
+            dv1.field::<EmpName>().to_vec(),
+            vec!["Ann", "Bob", "Cara", "Jamie", "Louis", "Louise", "Sally"]
+        );
+        assert_eq!(
+            dv1.field::<computed::HrsPerId>().to_vec(),
+            vec![
+                22.5 / 11.0,
+                98.3 / 6.0,
+                12.2 / 7.0,
+                54.1 / 3.0,
+                -1.2 / 9.0,
+                5.4 / 10.0,
+                47.3
+            ]
+        );
+
+        // missing values propagate
+        let dv2 = dv
+            .compute::<Labels![computed::Summary, EmpName], computed::HrsPerId, _>(
+                |summary: &String, name: &String| (summary.len() + name.len()) as f32,
+            )
+            .unwrap();
+        assert_eq!(
+            dv2.field::<computed::HrsPerId>().iter().collect::<Vec<_>>(),
+            vec![
+                valref![4.0],
+                valref![4.0],
+                Value::Na,
+                valref![6.0],
+                valref![6.0],
+                valref![7.0],
+                valref![6.0]
+            ]
+        );
+
+        // handle missing values in function
+        let dv3 = dv
+            .compute_values::<Labels![computed::Summary, DeptId], computed::HrsPerId, _>(
+                |summary: Value<&String>, dept: Value<&u64>| match (summary, dept) {
+                    (Value::Exists(_), Value::Exists(&dept)) if dept > 2 => Value::Na,
+                    (Value::Exists(summary), Value::Exists(&dept)) => {
+                        Value::Exists(summary.len() as f32 + dept as f32)
+                    }
+                    (Value::Na, _) | (_, Value::Na) => Value::Exists(0.0),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            dv3.field::<computed::HrsPerId>().iter().collect::<Vec<_>>(),
+            vec![
+                Value::Na,
+                valref![2.0],
+                valref![0.0],
+                valref![3.0],
+                Value::Na,
+                Value::Na,
+                valref![2.0]
+            ]
+        );
+    }
+
+    #[cfg(feature = "test-utils")]
+    namespace![
+        @continue(typenum::Add1<::test_utils::dept_table::Table>)
+        pub table str_tbl {
+            NameUpper: String,
+            NameLen: usize,
+        }
+    ];
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn string_ops() {
+        use ops::{FieldStr, Regex};
+        use test_utils::emp_table::*;
+        let mut dv = sample_emp_table().into_view();
+
+        let mask = dv.field::<EmpName>().starts_with("Lou");
+        assert_eq!(dv.filter_mask(&mask).unwrap(), vec![4, 5]);
+        assert_eq!(dv.field::<EmpName>().to_vec(), vec!["Louis", "Louise"]);
+
+        let upper = dv.field::<EmpName>().to_uppercase();
+        let len = dv.field::<EmpName>().str_len();
+        let dv = dv
+            .with_field::<str_tbl::NameUpper, _>(upper)
+            .unwrap()
+            .with_field::<str_tbl::NameLen, _>(len)
+            .unwrap();
+        assert_eq!(
+            dv.field::<str_tbl::NameUpper>().to_vec(),
+            vec!["LOUIS", "LOUISE"]
+        );
+        assert_eq!(dv.field::<str_tbl::NameLen>().to_vec(), vec![5, 6]);
+
+        let mut dv = sample_emp_table().into_view();
+        let mask = dv
+            .field::<EmpName>()
+            .is_match(&Regex::new("^[A-C]").unwrap());
+        dv.filter_mask(&mask).unwrap();
+        assert_eq!(dv.field::<EmpName>().to_vec(), vec!["Bob", "Cara", "Ann"]);
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn concat() {
+        use field::FieldData;
+        use stats::NaCount;
+        use test_utils::emp_table::*;
+        let mut dv1 = sample_emp_table().into_view();
+        dv1.filter::<DeptId, _>(|val: Value<&u64>| val == 1);
+        let ds: emp_table::Store = emp_table_from_field![
+            FieldData::from(vec![20u64, 21]),
+            FieldData::from_field_vec(vec![Value::Na, Value::Exists(5u64)]),
+            FieldData::from(vec!["Tom".to_string(), "Kim".to_string()])
+        ];
+        let dv2 = ds.into_view();
+
+        let cat_dv = dv1.concat(&dv2);
+        assert_eq!(cat_dv.nframes(), 1);
+        assert_eq!(cat_dv.nrows(), 5);
+        assert_eq!(cat_dv.fieldnames(), dv1.fieldnames());
+        assert_eq!(cat_dv.field::<EmpId>().to_vec(), vec![0u64, 5, 6, 20, 21]);
+        assert_eq!(
+            cat_dv.field::<DeptId>().iter().collect::<Vec<_>>(),
+            vec![valref![1u64], valref![1], valref![1], Value::Na, valref![5]]
+        );
+        assert_eq!(
+            cat_dv.field::<EmpName>().to_vec(),
+            vec!["Sally", "Bob", "Cara", "Tom", "Kim"]
+        );
+
+        let cat_all_dv = DataView::concat_all(vec![&dv2, &dv1, &dv2]);
+        assert_eq!(cat_all_dv.nrows(), 7);
+        assert_eq!(
+            cat_all_dv.field::<EmpId>().to_vec(),
+            vec![20u64, 21, 0, 5, 6, 20, 21]
+        );
+        assert_eq!(cat_all_dv.field::<DeptId>().num_na(), 2);
+
+        let empty_dv = DataView::concat_all(Vec::<&emp_table::View>::new());
+        assert_eq!(empty_dv.nrows(), 0);
+    }
+
     #[cfg(feature = "test-utils")]
     #[test]
     fn filter_sort() {
@@ -1594,4 +3141,191 @@ mod tests {
             vec![false, false, true, true, false, true]
         );
     }
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn distinct_duplicated() {
+        let orig_dv = sample_merged_emp_table();
+
+        // the only repeat of department ID and training status is index 3 (a repeat of index 2)
+        assert_eq!(
+            orig_dv
+                .duplicated::<Labels![emp_table::DeptId, extra_emp::DidTraining]>(Keep::First)
+                .to_vec(),
+            vec![false, false, false, true, false, false, false]
+        );
+        assert_eq!(
+            orig_dv
+                .duplicated::<Labels![emp_table::DeptId, extra_emp::DidTraining]>(Keep::Last)
+                .to_vec(),
+            vec![false, false, true, false, false, false, false]
+        );
+
+        let mut dv = orig_dv.clone();
+        let perm = dv.distinct::<Labels![emp_table::DeptId, extra_emp::DidTraining]>(Keep::First);
+        assert_eq!(perm, vec![0, 1, 2, 4, 5, 6]);
+        assert_eq!(dv.nfields(), orig_dv.nfields());
+        assert_eq!(
+            dv.field::<emp_table::EmpName>().to_vec(),
+            vec!["Sally", "Jamie", "Bob", "Louis", "Louise", "Ann"]
+        );
+
+        let mut dv = orig_dv.clone();
+        let perm = dv.distinct::<Labels![emp_table::DeptId]>(Keep::Last);
+        assert_eq!(perm, vec![1, 3, 4, 6]);
+        assert_eq!(
+            dv.field::<emp_table::DeptId>().to_vec(),
+            vec![2u64, 1, 3, 4]
+        );
+        assert_eq!(
+            dv.field::<emp_table::EmpName>().to_vec(),
+            vec!["Jamie", "Cara", "Louis", "Ann"]
+        );
+
+        // distinct is relative to the current order of the view
+        let mut dv = orig_dv.clone();
+        dv.sort_by_label::<emp_table::EmpName>();
+        dv.distinct::<Labels![emp_table::DeptId]>(Keep::First);
+        assert_eq!(
+            dv.field::<emp_table::EmpName>().to_vec(),
+            vec!["Ann", "Bob", "Jamie", "Louis"]
+        );
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn record_eq() {
+        let dv = sample_merged_emp_table();
+        let fl = dv.field_list::<Labels![emp_table::DeptId, extra_emp::DidTraining]>();
+        // indices 2 and 3 share department ID and training status
+        assert!(Record::new(&fl, 2) == Record::new(&fl, 3));
+        // indices 0 and 2 share department ID, but not training status
+        assert!(Record::new(&fl, 0) != Record::new(&fl, 2));
+        // indices 0 and 1 differ in both
+        assert!(Record::new(&fl, 0) != Record::new(&fl, 1));
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn reset_order_row_ids() {
+        use test_utils::emp_table::*;
+        let mut dv = sample_emp_table().into_view();
+        assert_eq!(dv.row_ids(), vec![0, 1, 2, 3, 4, 5, 6]);
+
+        dv.filter::<DeptId, _>(|val: Value<&u64>| val == valref![1]);
+        dv.sort_by_label::<EmpName>();
+        assert_eq!(dv.field::<EmpName>().to_vec(), vec!["Bob", "Cara", "Sally"]);
+        assert_eq!(dv.row_ids(), vec![2, 3, 0]);
+
+        // checkpoint, then further restrict the view
+        let checkpoint = dv.order_checkpoint();
+        dv.head(1);
+        assert_eq!(dv.row_ids(), vec![2]);
+        dv.restore_order(checkpoint);
+        assert_eq!(dv.row_ids(), vec![2, 3, 0]);
+        assert_eq!(dv.field::<EmpName>().to_vec(), vec!["Bob", "Cara", "Sally"]);
+
+        dv.reset_order().unwrap();
+        assert_eq!(dv.nrows(), 7);
+        assert_eq!(dv.row_ids(), vec![0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(
+            dv.field::<EmpName>().to_vec(),
+            vec!["Sally", "Jamie", "Bob", "Cara", "Louis", "Louise", "Ann"]
+        );
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn reset_order_merged() {
+        let mut dv = sample_merged_emp_table();
+        dv.sort_by_label::<extra_emp::SalaryOffset>();
+        assert_eq!(
+            dv.frame_row_ids::<emp_table::EmpId>(),
+            vec![3, 0, 6, 5, 1, 4, 2]
+        );
+        assert_eq!(
+            dv.frame_row_ids::<extra_emp::VacationHrs>(),
+            vec![3, 0, 6, 5, 1, 4, 2]
+        );
+        dv.reset_order().unwrap();
+        assert_eq!(
+            dv.field::<extra_emp::VacationHrs>().to_vec(),
+            vec![47.3, 54.1, 98.3, 12.2, -1.2, 5.4, 22.5]
+        );
+
+        // a field added to a filtered view has a smaller data store than the original frames
+        let mut dv = sample_emp_table().into_view();
+        dv.filter::<emp_table::DeptId, _>(|val: Value<&u64>| val == valref![4]);
+        let len = FieldData::<usize>::from_vec(vec![6usize, 3]);
+        let mut dv = dv.with_field::<str_tbl::NameLen, _>(len).unwrap();
+        assert_eq!(dv.frame_row_ids::<emp_table::EmpId>(), vec![5, 6]);
+        assert_eq!(dv.frame_row_ids::<str_tbl::NameLen>(), vec![0, 1]);
+        match dv.reset_order() {
+            Err(AgnesError::DimensionMismatch(_)) => {}
+            _ => panic!("expected DimensionMismatch error"),
+        }
+        // view is unchanged
+        assert_eq!(dv.nrows(), 2);
+        assert_eq!(
+            dv.field::<emp_table::EmpName>().to_vec(),
+            vec!["Louise", "Ann"]
+        );
+
+        // frames of equal store lengths which were filtered differently before merging
+        let mut emp_dv = sample_emp_table().into_view();
+        emp_dv.filter::<emp_table::DeptId, _>(|val: Value<&u64>| val == valref![1]);
+        let mut extra_dv = sample_emp_table_extra().into_view();
+        extra_dv.filter::<extra_emp::DidTraining, _>(|val: Value<&bool>| val == valref![true]);
+        extra_dv.head(3);
+        let mut dv = emp_dv.merge(&extra_dv).unwrap();
+        assert_eq!(dv.frame_row_ids::<emp_table::EmpId>(), vec![0, 2, 3]);
+        assert_eq!(dv.frame_row_ids::<extra_emp::DidTraining>(), vec![2, 3, 4]);
+        match dv.reset_order() {
+            Err(AgnesError::DimensionMismatch(_)) => {}
+            _ => panic!("expected DimensionMismatch error"),
+        }
+        assert_eq!(
+            dv.field::<emp_table::EmpName>().to_vec(),
+            vec!["Sally", "Bob", "Cara"]
+        );
+        assert_eq!(
+            dv.field::<extra_emp::SalaryOffset>().to_vec(),
+            vec![12, -33, 10]
+        );
+    }
+
+    #[cfg(feature = "test-utils")]
+    namespace![
+        @continue(typenum::Add1<super::str_tbl::Table>)
+        pub table window_tbl {
+            MaxVacation: f64,
+        }
+    ];
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn rolling_field() {
+        use ops::{Rolling, Window};
+        let mut dv = sample_merged_emp_table();
+        // windows are formed in the current order of the view
+        dv.sort_by_label::<extra_emp::SalaryOffset>();
+        let max_vacation = dv
+            .field::<extra_emp::VacationHrs>()
+            .rolling_max(&Window::trailing(2));
+        let dv = dv
+            .with_field::<window_tbl::MaxVacation, _>(max_vacation)
+            .unwrap();
+        assert_eq!(
+            dv.field::<window_tbl::MaxVacation>().to_value_vec(),
+            vec![
+                Value::Na,
+                Value::Exists(47.3f32 as f64),
+                Value::Exists(47.3f32 as f64),
+                Value::Exists(22.5f32 as f64),
+                Value::Exists(54.1f32 as f64),
+                Value::Exists(54.1f32 as f64),
+                Value::Exists(98.3f32 as f64),
+            ]
+        );
+    }
 }
@@ -120,6 +120,18 @@ impl FuncDefault for StDevFn {
     }
 }
 
+macro_rules! impl_merge_fns {
+    ($($func:ty)*) => {$(
+        impl MergeFunc for $func {
+            fn merge(&mut self, other: $func) {
+                self.values.extend(other.values);
+            }
+        }
+    )*}
+}
+
+impl_merge_fns![MinFn MaxFn SumFn MeanFn StDevFn];
+
 macro_rules! impl_stats_fns {
     ($($dtype:ty)*) => {$(
 
@@ -221,24 +233,29 @@ impl<Labels, Frames> DataView<Labels, Frames>
 where
     Frames: Len + NRows + AssocDataIndexCons<Labels>,
     AssocDataIndexConsOf<Labels, Frames>: DeriveCapabilities<MinFn>,
+    DeriveCapabilitiesOf<Labels, Frames, MinFn>: ParallelPartialMap<MinFn>,
     AssocDataIndexConsOf<Labels, Frames>: DeriveCapabilities<MaxFn>,
+    DeriveCapabilitiesOf<Labels, Frames, MaxFn>: ParallelPartialMap<MaxFn>,
     AssocDataIndexConsOf<Labels, Frames>: DeriveCapabilities<SumFn>,
+    DeriveCapabilitiesOf<Labels, Frames, SumFn>: ParallelPartialMap<SumFn>,
     AssocDataIndexConsOf<Labels, Frames>: DeriveCapabilities<MeanFn>,
+    DeriveCapabilitiesOf<Labels, Frames, MeanFn>: ParallelPartialMap<MeanFn>,
     AssocDataIndexConsOf<Labels, Frames>: DeriveCapabilities<StDevFn>,
+    DeriveCapabilitiesOf<Labels, Frames, StDevFn>: ParallelPartialMap<StDevFn>,
     Labels: Len + StrLabels + StrTypes,
 {
     /// Compute and return general statistics for this `DataView`.
     pub fn view_stats(&self) -> ViewStats {
         let mut min_fn = MinFn::default();
-        DeriveCapabilities::<MinFn>::derive(self.frames.assoc_data()).map(&mut min_fn);
+        DeriveCapabilities::<MinFn>::derive(self.frames.assoc_data()).par_map(&mut min_fn);
         let mut max_fn = MaxFn::default();
-        DeriveCapabilities::<MaxFn>::derive(self.frames.assoc_data()).map(&mut max_fn);
+        DeriveCapabilities::<MaxFn>::derive(self.frames.assoc_data()).par_map(&mut max_fn);
         let mut sum_fn = SumFn::default();
-        DeriveCapabilities::<SumFn>::derive(self.frames.assoc_data()).map(&mut sum_fn);
+        DeriveCapabilities::<SumFn>::derive(self.frames.assoc_data()).par_map(&mut sum_fn);
         let mut mean_fn = MeanFn::default();
-        DeriveCapabilities::<MeanFn>::derive(self.frames.assoc_data()).map(&mut mean_fn);
+        DeriveCapabilities::<MeanFn>::derive(self.frames.assoc_data()).par_map(&mut mean_fn);
         let mut stdev_fn = StDevFn::default();
-        DeriveCapabilities::<StDevFn>::derive(self.frames.assoc_data()).map(&mut stdev_fn);
+        DeriveCapabilities::<StDevFn>::derive(self.frames.assoc_data()).par_map(&mut stdev_fn);
 
         let view_stats = ViewStats {
             nrows: self.nrows(),
@@ -367,4 +384,31 @@ mod tests {
         assert_float_eq!(vs2.means[2], 34.0857143); // VacationHrs mean
         assert_float_eq!(vs2.stdevs[2], 35.070948); // VacationHrs stdev
     }
+
+    #[test]
+    fn view_stats_merged() {
+        // stats are computed for each field in field order, across frames
+        let dv = sample_merged_emp_table();
+        let vs = dv.view_stats();
+        assert_eq!(vs.nrows, 7);
+        assert_eq!(vs.nfields, 6);
+        assert_eq!(
+            vs.idents,
+            vec![
+                "EmpId",
+                "DeptId",
+                "EmpName",
+                "SalaryOffset",
+                "DidTraining",
+                "VacationHrs"
+            ]
+        );
+        assert_eq!(vs.sums[..5], ["40", "16", "", "-13", ""]);
+        assert_float_eq!(vs.sums[5], 238.6);
+        assert_eq!(vs.mins, vec!["0", "1", "", "-33", "", "-1.2"]);
+        assert_eq!(vs.maxs, vec!["10", "4", "", "12", "", "98.3"]);
+        assert_float_eq!(vs.means[3], -1.857143);
+        assert_float_eq!(vs.stdevs[5], 35.070948);
+        assert_eq!(vs.means[4], "");
+    }
 }
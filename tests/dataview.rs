@@ -119,3 +119,82 @@ fn merge() {
         vec!["CountryName", "CountryCode", "Gdp1983", "Life1983"]
     );
 }
+
+namespace![
+    pub table gdp_wide {
+        CountryCode: String,
+        Year1960: f64 = {"1960"},
+        Year1961: f64 = {"1961"},
+        Year1962: f64 = {"1962"},
+    }
+    pub table gdp_long {
+        Year: String,
+        Gdp: f64,
+    }
+];
+
+#[test]
+fn melt() {
+    use agnes::access::DataIndex;
+    use agnes::field::Value;
+    use agnes::select::FieldSelect;
+    use gdp_wide::*;
+
+    let gdp_spec = spec![
+        fieldname CountryCode = "Country Code";
+        fieldname Year1960 = "1960";
+        fieldname Year1961 = "1961";
+        fieldname Year1962 = "1962";
+    ];
+    let (mut csv_rdr, _) = common::load_csv_file("gdp.csv", gdp_spec);
+    let dv = csv_rdr.read().unwrap().into_view();
+    assert_eq!(dv.nrows(), 264);
+
+    let dv_long = dv
+        .melt::<Labels![CountryCode], Labels![Year1960, Year1961, Year1962], gdp_long::Year, gdp_long::Gdp>()
+        .unwrap();
+    assert_eq!(dv_long.nrows(), 264 * 3);
+    assert_eq!(dv_long.fieldnames(), vec!["CountryCode", "Year", "Gdp"]);
+
+    let codes = dv_long.field::<CountryCode>();
+    let years = dv_long.field::<gdp_long::Year>();
+    let gdps = dv_long.field::<gdp_long::Gdp>();
+    // Aruba (first record) is missing GDP data for these years
+    assert_eq!(
+        codes.get_datum(0).unwrap(),
+        Value::Exists(&"ABW".to_string())
+    );
+    assert_eq!(
+        codes.get_datum(264).unwrap(),
+        Value::Exists(&"ABW".to_string())
+    );
+    assert!(gdps.get_datum(0).unwrap().is_na());
+    for (i, year) in ["1960", "1961", "1962"].iter().enumerate() {
+        assert_eq!(
+            years.get_datum(i * 264).unwrap(),
+            Value::Exists(&year.to_string())
+        );
+        assert_eq!(
+            years.get_datum(i * 264 + 263).unwrap(),
+            Value::Exists(&year.to_string())
+        );
+    }
+    // United States
+    assert_eq!(
+        codes.get_datum(249).unwrap(),
+        Value::Exists(&"USA".to_string())
+    );
+    assert_eq!(
+        codes.get_datum(264 + 249).unwrap(),
+        Value::Exists(&"USA".to_string())
+    );
+    assert_eq!(gdps.get_datum(249).unwrap(), Value::Exists(&543300000000.0));
+    assert_eq!(
+        gdps.get_datum(264 + 249).unwrap(),
+        Value::Exists(&563300000000.0)
+    );
+    assert_eq!(
+        gdps.get_datum(2 * 264 + 249).unwrap(),
+        Value::Exists(&605100000000.0)
+    );
+}